@@ -0,0 +1,197 @@
+// src/alert_state.rs
+use std::{error::Error, fs, path::Path};
+
+use chrono::{DateTime, Duration as ChronoDuration, Local, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::{escalation::EscalationPolicy, notification::CheckResult};
+
+/// 알림을 보낸 누락 항목 하나. 워크북/시트/행/예정 일시로 구분합니다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlertRecord {
+    pub workbook: String,
+    pub sheet: String,
+    pub row: usize,
+    pub scheduled: NaiveDateTime,
+    pub first_notified: DateTime<Local>,
+    pub last_notified: DateTime<Local>,
+    /// 마지막으로 알린 알림 단계 번호
+    #[serde(default)]
+    pub tier: usize,
+}
+
+impl AlertRecord {
+    fn is_same(&self, workbook: &str, sheet: &str, row: usize, scheduled: NaiveDateTime) -> bool {
+        self.workbook == workbook
+            && self.sheet == sheet
+            && self.row == row
+            && self.scheduled == scheduled
+    }
+}
+
+/// 검사 결과를 이전 알림 상태와 비교한 결과.
+#[derive(Debug, Default)]
+pub struct AlertUpdate {
+    /// 처음 발견된 누락 항목
+    pub new: Vec<AlertRecord>,
+    /// 재알림 주기가 지나 다시 알릴 항목
+    pub reminders: Vec<AlertRecord>,
+    /// 지연 시간이 늘어 더 높은 알림 단계로 올라간 항목
+    pub escalated: Vec<AlertRecord>,
+    /// 완료 처리되어 더 이상 누락이 아닌 항목
+    pub resolved: Vec<AlertRecord>,
+}
+
+impl AlertUpdate {
+    /// 알림을 보내야 하는지 여부.
+    pub fn should_notify(&self) -> bool {
+        !self.new.is_empty()
+            || !self.reminders.is_empty()
+            || !self.escalated.is_empty()
+            || !self.resolved.is_empty()
+    }
+
+    /// 이번에 누락으로 알릴 항목 (새 누락, 재알림, 단계 상승).
+    pub fn announced(&self) -> impl Iterator<Item = &AlertRecord> {
+        self.new
+            .iter()
+            .chain(&self.reminders)
+            .chain(&self.escalated)
+    }
+}
+
+/// 실행 간에 유지되는 알림 상태. missing.txt 옆의 JSON 파일에 저장합니다.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AlertState {
+    alerts: Vec<AlertRecord>,
+}
+
+impl AlertState {
+    /// 상태 파일 읽기. 파일이 없거나 깨져 있으면 빈 상태로 시작합니다.
+    pub fn load(path: &Path) -> Self {
+        let data = match fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(_) => return AlertState::default(),
+        };
+        match serde_json::from_str(&data) {
+            Ok(state) => state,
+            Err(e) => {
+                warn!(
+                    "알림 상태 파일을 읽을 수 없어 새로 시작합니다: {}: {}",
+                    path.display(),
+                    e
+                );
+                AlertState::default()
+            }
+        }
+    }
+
+    /// 상태 파일 저장. 쓰는 도중 종료되어도 이전 파일이 남도록 임시 파일에 쓴 뒤 바꿔치기합니다.
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    /// 현재 알림 중인 항목.
+    pub fn records(&self) -> &[AlertRecord] {
+        &self.alerts
+    }
+
+    /// 현재 알림 중인 항목 개수.
+    pub fn open_count(&self) -> usize {
+        self.alerts.len()
+    }
+
+    /// 검사 결과를 반영합니다.
+    ///
+    /// 검사하지 못한 워크북/시트의 항목은 그대로 두고, 설정에서 빠진 워크북/시트와 검사 기간을 벗어난 항목은
+    /// 해결 알림 없이 정리합니다.
+    /// `reminder`가 None이면 같은 항목을 다시 알리지 않습니다.
+    /// 지연 시간이 늘어 알림 단계가 올라간 항목은 재알림 주기와 관계없이 바로 다시 알립니다.
+    pub fn update(
+        &mut self,
+        result: &CheckResult,
+        now: DateTime<Local>,
+        reminder: Option<ChronoDuration>,
+        policy: &EscalationPolicy,
+    ) -> AlertUpdate {
+        let mut update = AlertUpdate::default();
+        let mut remaining = Vec::new();
+
+        // 이전 항목: 아직 누락이면 유지(필요하면 재알림), 아니면 해결 처리
+        for mut record in self.alerts.drain(..) {
+            let Some(workbook) = result.workbooks.iter().find(|w| w.name == record.workbook) else {
+                // 설정에서 빠진 워크북
+                debug!("알림 상태 정리 (워크북 없음): {:?}", record);
+                continue;
+            };
+            if !workbook.sheets.contains(&record.sheet) {
+                // 설정에서 빠진 시트
+                debug!("알림 상태 정리 (시트 없음): {:?}", record);
+                continue;
+            }
+            let checked =
+                workbook.error.is_none() && !workbook.sheet_errors.contains_key(&record.sheet);
+            let current = workbook.missing.get(&record.sheet).and_then(|entries| {
+                entries
+                    .iter()
+                    .find(|e| record.is_same(&workbook.name, &e.sheet, e.row, e.scheduled))
+            });
+
+            if let Some(entry) = current {
+                let tier = policy.tier_index(entry.overdue);
+                if tier > record.tier {
+                    record.tier = tier;
+                    record.last_notified = now;
+                    update.escalated.push(record.clone());
+                } else if reminder.is_some_and(|cadence| now - record.last_notified >= cadence) {
+                    record.last_notified = now;
+                    update.reminders.push(record.clone());
+                }
+                remaining.push(record);
+            } else if !checked {
+                remaining.push(record);
+            } else if record.scheduled < result.window_start {
+                debug!("알림 상태 정리 (검사 기간 지남): {:?}", record);
+            } else {
+                update.resolved.push(record);
+            }
+        }
+
+        // 새로 발견된 항목
+        for workbook in &result.workbooks {
+            for entry in workbook.missing.values().flatten() {
+                let known = remaining
+                    .iter()
+                    .any(|r| r.is_same(&workbook.name, &entry.sheet, entry.row, entry.scheduled));
+                if !known {
+                    let record = AlertRecord {
+                        workbook: workbook.name.clone(),
+                        sheet: entry.sheet.clone(),
+                        row: entry.row,
+                        scheduled: entry.scheduled,
+                        first_notified: now,
+                        last_notified: now,
+                        tier: policy.tier_index(entry.overdue),
+                    };
+                    update.new.push(record.clone());
+                    remaining.push(record);
+                }
+            }
+        }
+
+        self.alerts = remaining;
+        info!(
+            "알림 상태: 새 누락 {}개, 재알림 {}개, 단계 상승 {}개, 해결 {}개, 진행 중 {}개",
+            update.new.len(),
+            update.reminders.len(),
+            update.escalated.len(),
+            update.resolved.len(),
+            self.alerts.len()
+        );
+        update
+    }
+}
@@ -0,0 +1,48 @@
+// src/clock.rs
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration as ChronoDuration, Local};
+
+/// 현재 시각 공급자. 누락 검사와 스케줄러는 `Local::now()` 대신 이 트레잇으로 시각을 얻습니다.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Local>;
+}
+
+/// 시스템 시계.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+}
+
+/// 지정한 시각에 고정된 시계. `set`/`advance`로 시각을 옮길 수 있습니다.
+#[derive(Debug)]
+pub struct FixedClock {
+    now: Mutex<DateTime<Local>>,
+}
+
+impl FixedClock {
+    pub fn new(now: DateTime<Local>) -> Self {
+        FixedClock {
+            now: Mutex::new(now),
+        }
+    }
+
+    pub fn set(&self, now: DateTime<Local>) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    pub fn advance(&self, duration: ChronoDuration) {
+        let mut now = self.now.lock().unwrap();
+        *now += duration;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Local> {
+        *self.now.lock().unwrap()
+    }
+}
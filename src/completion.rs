@@ -0,0 +1,148 @@
+// src/completion.rs
+use calamine::DataType;
+use regex::Regex;
+
+use crate::consts::{DEFAULT_DONE_VALUES, DEFAULT_PENDING_VALUES, DEFAULT_SKIP_VALUES};
+
+/// 완료 열 값으로 판단한 행 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowStatus {
+    /// 완료됨. 누락 검사 대상 아님
+    Done,
+    /// 취소/생략됨. 누락 검사 대상 아님
+    Skipped,
+    /// 미완료 (빈 값, 보류, `any_value = false`일 때 목록에 없는 값). 누락 검사 대상
+    Open,
+}
+
+/// 완료 열 값 판단 규칙. `[completion]` 섹션에서 설정합니다.
+///
+/// 값 목록은 대소문자와 앞뒤 공백을 무시하고 비교합니다.
+/// 불리언 셀은 TRUE면 완료, 숫자 셀은 0이 아니면 완료로 봅니다.
+/// 제외/보류 목록을 먼저 확인하고, 그 밖의 비어 있지 않은 값은 완료로 봅니다(이전 동작).
+/// `any_value = false`이면 완료 목록이나 정규식에 맞는 값만 완료로 보고 나머지는 미완료로 처리합니다.
+#[derive(Debug, Clone)]
+pub struct CompletionRules {
+    /// 완료로 보는 값 (`done = 완료, O, Y`)
+    pub done_values: Vec<String>,
+    /// 검사에서 제외할 값 (`skip = 취소, 생략`)
+    pub skip_values: Vec<String>,
+    /// 미완료로 명시된 값 (`pending = 보류, 대기`)
+    pub pending_values: Vec<String>,
+    /// 완료로 보는 정규식 (`done_pattern = ^완료`)
+    pub done_pattern: Option<Regex>,
+    /// 검사에서 제외할 정규식 (`skip_pattern = ^취소`)
+    pub skip_pattern: Option<Regex>,
+    /// 제외/보류 값이 아닌 모든 값을 완료로 봄 (기본값 true, `any_value = false`로 끄기)
+    pub any_value: bool,
+}
+
+impl Default for CompletionRules {
+    fn default() -> Self {
+        CompletionRules {
+            done_values: normalize_values(DEFAULT_DONE_VALUES),
+            skip_values: normalize_values(DEFAULT_SKIP_VALUES),
+            pending_values: normalize_values(DEFAULT_PENDING_VALUES),
+            done_pattern: None,
+            skip_pattern: None,
+            any_value: true,
+        }
+    }
+}
+
+impl CompletionRules {
+    /// `[completion]` 섹션의 항목 하나를 적용합니다. 값 목록은 쉼표로 구분합니다. 알 수 없는 항목이면 Ok(false).
+    pub fn apply(&mut self, key: &str, value: &str) -> Result<bool, String> {
+        match key {
+            "done" | "skip" | "pending" => {
+                let values: Vec<&str> = value.split(',').collect();
+                return Ok(self.set_values(key, &values));
+            }
+            "done_pattern" => self.done_pattern = Some(parse_pattern(value)?),
+            "skip_pattern" => self.skip_pattern = Some(parse_pattern(value)?),
+            "any_value" => {
+                self.any_value = match value.to_lowercase().as_str() {
+                    "true" | "yes" | "1" => true,
+                    "false" | "no" | "0" => false,
+                    _ => return Err(format!("true 또는 false여야 합니다: '{}'", value)),
+                }
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// `done`, `skip`, `pending` 값 목록 설정. 알 수 없는 항목이면 false.
+    pub fn set_values(&mut self, key: &str, values: &[&str]) -> bool {
+        let values = normalize_values(values);
+        match key {
+            "done" => self.done_values = values,
+            "skip" => self.skip_values = values,
+            "pending" => self.pending_values = values,
+            _ => return false,
+        }
+        true
+    }
+
+    /// 완료 열 셀의 상태.
+    pub fn classify(&self, cell: Option<&DataType>) -> RowStatus {
+        match cell {
+            None | Some(DataType::Empty) => RowStatus::Open,
+            Some(DataType::Bool(true)) => RowStatus::Done,
+            Some(DataType::Bool(false)) => RowStatus::Open,
+            Some(DataType::Int(i)) => done_if(*i != 0),
+            Some(DataType::Float(f)) => done_if(*f != 0.0),
+            Some(DataType::String(s)) => self.classify_text(s),
+            Some(DataType::Error(_)) => RowStatus::Open,
+            // 날짜 등 그 밖의 값은 완료 처리 일시를 적어 둔 것으로 봄
+            Some(_) => RowStatus::Done,
+        }
+    }
+
+    fn classify_text(&self, text: &str) -> RowStatus {
+        let text = text.trim();
+        if text.is_empty() {
+            return RowStatus::Open;
+        }
+
+        let lower = text.to_lowercase();
+        let contains = |values: &[String]| values.contains(&lower);
+        if contains(&self.skip_values) || matches_pattern(&self.skip_pattern, text) {
+            RowStatus::Skipped
+        } else if contains(&self.pending_values) {
+            RowStatus::Open
+        } else if self.any_value
+            || contains(&self.done_values)
+            || matches_pattern(&self.done_pattern, text)
+        {
+            RowStatus::Done
+        } else {
+            RowStatus::Open
+        }
+    }
+}
+
+fn done_if(done: bool) -> RowStatus {
+    if done {
+        RowStatus::Done
+    } else {
+        RowStatus::Open
+    }
+}
+
+fn matches_pattern(pattern: &Option<Regex>, text: &str) -> bool {
+    pattern.as_ref().is_some_and(|p| p.is_match(text))
+}
+
+// 값 목록을 비교할 수 있게 소문자로 정리
+fn normalize_values(values: &[&str]) -> Vec<String> {
+    values
+        .iter()
+        .map(|v| v.trim().to_lowercase())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+fn parse_pattern(value: &str) -> Result<Regex, String> {
+    Regex::new(value).map_err(|e| format!("잘못된 정규식 '{}': {}", value, e))
+}
@@ -0,0 +1,1666 @@
+// src/config_file.rs
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    slice,
+    time::Duration,
+};
+
+use serde::Deserialize;
+use toml::Spanned;
+use tracing::{error, info, warn};
+
+use crate::{
+    completion::CompletionRules,
+    consts::{
+        CONFIG_FILE_NAME, CONFIG_TOML_FILE_NAME, DEFAULT_GRACE_MINUTES, DEFAULT_MESSAGE,
+        DEFAULT_REMINDER_MINUTES, DEFAULT_TITLE, TOAST_NOTIFIER,
+    },
+    escalation::{EscalationPolicy, EscalationTier, channel_list, parse_channel_list},
+    formats::DateTimeFormats,
+    notifier::{NotifierConfig, NotifierKind, builtin_notifiers},
+    schedule::{CronExpr, Schedule},
+    snapshot::SnapshotSettings,
+    source::SourceFormat,
+    template::Template,
+    utils::{ColumnRef, Config, Lookback, SheetConfig, WorkbookConfig, WorkbookSection},
+    watcher::WatchSettings,
+};
+
+// 실행 파일 폴더의 설정 파일. config.toml이 있으면 config.cfg보다 우선
+pub fn find_config_file(dir: &Path) -> PathBuf {
+    let toml_path = dir.join(CONFIG_TOML_FILE_NAME);
+    let cfg_path = dir.join(CONFIG_FILE_NAME);
+    if !toml_path.exists() {
+        return cfg_path;
+    }
+    if cfg_path.exists() {
+        warn!(
+            "설정 파일이 두 개 있습니다({}, {}). {}을(를) 사용합니다.",
+            CONFIG_TOML_FILE_NAME, CONFIG_FILE_NAME, CONFIG_TOML_FILE_NAME
+        );
+    }
+    toml_path
+}
+
+/// 읽을 설정 파일과 명령줄에서 덮어쓴 값. 실행 중 설정을 다시 읽을 때도 같은 값을 적용합니다.
+#[derive(Debug, Clone)]
+pub struct ConfigSource {
+    pub path: PathBuf,
+    /// `--excel`로 지정한 엑셀 파일. 설정의 워크북 경로 대신 사용
+    pub excel: Option<PathBuf>,
+}
+
+impl ConfigSource {
+    pub fn load(&self) -> Result<Config, Box<dyn Error>> {
+        let mut config = read_config(&self.path)?;
+        if let Some(excel) = &self.excel {
+            let [workbook] = config.workbooks.as_mut_slice() else {
+                return Err(format!(
+                    "--excel은 워크북이 하나일 때만 쓸 수 있습니다. 설정된 워크북: {}개",
+                    config.workbooks.len()
+                )
+                .into());
+            };
+            info!(
+                "워크북 '{}' 경로를 {}(으)로 바꿔 사용합니다.",
+                workbook.name,
+                excel.display()
+            );
+            workbook.path = excel.clone();
+            // 형식은 새 파일의 확장자로 판단
+            workbook.format = None;
+        }
+        Ok(config)
+    }
+}
+
+// 설정 파일 읽기. 확장자가 .toml이면 TOML, 그 외는 이전 INI(.cfg) 형식
+pub fn read_config(path: &Path) -> Result<Config, Box<dyn Error>> {
+    if !path.exists() {
+        error!("설정 파일({})을 찾을 수 없습니다.", path.display());
+        return Err(format!("설정 파일({})을 찾을 수 없습니다.", path.display()).into());
+    }
+
+    let source = fs::read_to_string(path)?;
+    let is_toml = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"));
+    let builder = if is_toml {
+        parse_toml(&source)?
+    } else {
+        read_ini_config(&source)?
+    };
+    builder.build()
+}
+
+/// INI(.cfg) 설정 파일의 한 줄. 값 오류는 원래 파일의 행 번호로 보고됩니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigLine {
+    /// `[schedule]`, `[sheet:<이름>]` 등. `header`는 대괄호 안의 내용
+    Section { line: usize, header: String },
+    /// 섹션 안의 `key = value` 줄, 또는 `[title]`, `[message]`처럼 값만 있는 줄
+    Entry { line: usize, text: String },
+}
+
+// 섹션별로 사용할 수 있는 항목. INI 설정에서 알 수 없는 항목을 알릴 때 비슷한 이름을 찾는 데 사용
+const SECTION_KEYS: [(&str, &[&str]); 12] = [
+    ("schedule", &["minutes", "cron", "interval", "offset"]),
+    ("grace", &["minutes"]),
+    ("lookback", &["since", "hours"]),
+    ("formats", &["date", "time", "datetime"]),
+    (
+        "completion",
+        &[
+            "done",
+            "skip",
+            "pending",
+            "done_pattern",
+            "skip_pattern",
+            "any_value",
+        ],
+    ),
+    (
+        "snapshot",
+        &["dir", "retries", "backoff_ms", "stale_minutes"],
+    ),
+    ("alert", &["reminder_minutes"]),
+    ("notify", &["channels"]),
+    ("watch", &["enabled", "poll_seconds", "debounce_seconds"]),
+    ("workbook", &["path", "format", "sheets"]),
+    (
+        "sheet",
+        &[
+            "date",
+            "time",
+            "datetime",
+            "done",
+            "description",
+            "header_row",
+            "grace",
+        ],
+    ),
+    ("tier", &["after_minutes", "title", "message", "channels"]),
+];
+
+// [notifier:<이름>] 항목. 종류마다 쓰는 항목이 다름
+const NOTIFIER_KEYS: [&str; 21] = [
+    "type",
+    "path",
+    "command",
+    "arg",
+    "args",
+    "url",
+    "card",
+    "header",
+    "headers",
+    "body",
+    "timeout_seconds",
+    "retries",
+    "backoff_ms",
+    "host",
+    "port",
+    "security",
+    "username",
+    "password",
+    "from",
+    "to",
+    "digest_at",
+];
+
+// 항목 없이 값만 적는 섹션
+const VALUE_SECTIONS: [&str; 4] = ["target_path", "manage_game", "title", "message"];
+// 템플릿 섹션. 빈 줄과 `#`, `;`로 시작하는 줄도 템플릿 내용으로 봄
+const TEMPLATE_SECTIONS: [&str; 2] = ["title", "message"];
+
+/// INI 형식 설정. 빈 줄과 `;`, `#` 주석은 건너뛰고, 알 수 없는 섹션은 경고 후 무시합니다.
+/// `[title]`, `[message]` 섹션 안의 줄은 빈 줄과 `#`, `;`로 시작하는 줄까지 그대로 전달합니다.
+pub fn parse_ini(source: &str) -> Vec<ConfigLine> {
+    let mut lines = Vec::new();
+    let mut in_template = false;
+    for (line_idx, raw_line) in source.lines().enumerate() {
+        let line_no = line_idx + 1;
+        let line = raw_line.trim();
+        let is_header = line.starts_with('[') && line.ends_with(']');
+        if !in_template
+            && !is_header
+            && (line.is_empty() || line.starts_with(';') || line.starts_with('#'))
+        {
+            continue;
+        }
+        if is_header {
+            let header = line[1..line.len() - 1].trim().to_string();
+            let section = header
+                .split_once(':')
+                .map_or(header.as_str(), |(prefix, _)| prefix)
+                .trim()
+                .to_lowercase();
+            in_template = TEMPLATE_SECTIONS.contains(&section.as_str());
+            if !is_known_section(&section) {
+                let known = SECTION_KEYS
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .chain(VALUE_SECTIONS)
+                    .chain(["notifier"]);
+                warn!(
+                    "설정 파일 {}행: 알 수 없는 섹션 [{}] 무시{}",
+                    line_no,
+                    header,
+                    suggestion(&section, known)
+                );
+            }
+            lines.push(ConfigLine::Section {
+                line: line_no,
+                header,
+            });
+            continue;
+        }
+        // [message]의 들여쓰기를 유지하도록 앞쪽 공백은 남김
+        lines.push(ConfigLine::Entry {
+            line: line_no,
+            text: raw_line.trim_end().to_string(),
+        });
+    }
+    lines
+}
+
+fn is_known_section(section: &str) -> bool {
+    section == "notifier"
+        || VALUE_SECTIONS.contains(&section)
+        || SECTION_KEYS.iter().any(|(name, _)| *name == section)
+}
+
+/// 알 수 없는 항목 경고. 비슷한 이름의 항목이 있으면 함께 알려 줍니다.
+///
+/// `section`은 `schedule`, `sheet:<이름>`처럼 로그에 표시할 섹션 이름입니다.
+pub fn warn_unknown_key(line_no: usize, section: &str, key: &str) {
+    let prefix = section
+        .split_once(':')
+        .map_or(section, |(prefix, _)| prefix);
+    let keys: &[&str] = if prefix == "notifier" {
+        &NOTIFIER_KEYS
+    } else {
+        SECTION_KEYS
+            .iter()
+            .find(|(name, _)| *name == prefix)
+            .map_or(&[], |(_, keys)| *keys)
+    };
+    let hint = if keys.contains(&key) {
+        " (이 알림 종류에서는 쓰지 않는 항목입니다)".to_string()
+    } else {
+        suggestion(key, keys.iter().copied())
+    };
+    warn!(
+        "설정 파일 {}행: [{}]의 알 수 없는 항목 '{}' 무시{}",
+        line_no, section, key, hint
+    );
+}
+
+// 오타로 보이면 " ('minutes'을(를) 잘못 쓴 것 아닌가요?)"
+fn suggestion<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> String {
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance > 0 && *distance <= 2 && *distance < name.len())
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| format!(" ('{}'을(를) 잘못 쓴 것 아닌가요?)", candidate))
+        .unwrap_or_default()
+}
+
+// 레벤슈타인 거리
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = previous[j] + usize::from(ca != *cb);
+            current.push(substitute.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+// 이전 INI(.cfg) 형식 설정 해석
+fn read_ini_config(source: &str) -> Result<ConfigBuilder, Box<dyn Error>> {
+    let mut builder = ConfigBuilder::default();
+    let mut excel_path_str = None;
+    let mut manage_games = Vec::new();
+    // [message]는 여러 줄 템플릿. (첫 줄 번호, 줄 목록)
+    let mut message_lines: Option<(usize, Vec<String>)> = None;
+    // [tier:<이름>] message 항목. 여러 번 지정하면 줄을 이어 붙임
+    let mut tier_messages: HashMap<String, (usize, String)> = HashMap::new();
+    let mut defined_notifiers: Vec<String> = Vec::new();
+    let mut typed_notifiers: Vec<String> = Vec::new();
+    let mut current_section = "".to_string();
+    // [sheet:<이름>], [workbook:<이름>], [tier:<이름>], [notifier:<이름>] 섹션의 이름
+    let mut current_name: Option<String> = None;
+
+    for config_line in parse_ini(source) {
+        let (line_no, raw_line) = match config_line {
+            ConfigLine::Section {
+                line: line_no,
+                header,
+            } => {
+                let section = header.as_str();
+                // [sheet:<시트 이름>], [workbook:<워크북 이름>], [tier:<단계 이름>], [notifier:<이름>] 은
+                // 이름의 대소문자를 유지
+                match section.split_once(':') {
+                    Some((prefix, name))
+                        if ["sheet", "workbook", "tier", "notifier"]
+                            .iter()
+                            .any(|p| prefix.trim().eq_ignore_ascii_case(p)) =>
+                    {
+                        current_section = prefix.trim().to_lowercase();
+                        current_name = Some(name.trim().to_string());
+                    }
+                    _ => {
+                        current_section = section.to_lowercase();
+                        current_name = None;
+                    }
+                }
+                if current_section == "notifier" {
+                    let name = current_name.clone().unwrap_or_default();
+                    if defined_notifiers.contains(&name) {
+                        return Err(format!(
+                            "설정 파일 {}행: [notifier:{}] 섹션이 중복되었습니다.",
+                            line_no, name
+                        )
+                        .into());
+                    }
+                    defined_notifiers.push(name);
+                }
+                if current_section == "tier" {
+                    let name = current_name.clone().unwrap_or_default();
+                    if builder.tiers.iter().any(|t| t.name == name) {
+                        return Err(format!(
+                            "설정 파일 {}행: [tier:{}] 섹션이 중복되었습니다.",
+                            line_no, name
+                        )
+                        .into());
+                    }
+                    builder.tiers.push(EscalationTier::new(name));
+                }
+                if current_section == "workbook" {
+                    let name = current_name.clone().unwrap_or_default();
+                    if builder.workbook_settings.iter().any(|w| w.name == name) {
+                        return Err(format!(
+                            "설정 파일 {}행: [workbook:{}] 섹션이 중복되었습니다.",
+                            line_no, name
+                        )
+                        .into());
+                    }
+                    builder.workbook_settings.push(WorkbookSection {
+                        name,
+                        path: None,
+                        format: None,
+                        sheets: Vec::new(),
+                    });
+                }
+                continue;
+            }
+            ConfigLine::Entry { line, text } => (line, text),
+        };
+        let line = raw_line.trim().to_string();
+
+        match current_section.as_str() {
+            "target_path" => {
+                if let Some(first_path) = &excel_path_str {
+                    warn!(
+                        "[target_path]에 여러 경로가 지정됨. 첫 번째 경로만 사용: {}",
+                        first_path
+                    );
+                } else {
+                    excel_path_str = Some(line);
+                }
+            }
+            "manage_game" => {
+                manage_games.push(line);
+            }
+            "title" if line.is_empty() => {}
+            "title" => {
+                if builder.notification_title.is_none() {
+                    builder.notification_title = Some(Template::parse(&line).map_err(|e| {
+                        format!("설정 파일 {}행: [title] 템플릿 오류: {}", line_no, e)
+                    })?);
+                } else {
+                    warn!("[title]에 여러 줄이 지정됨. 첫 번째 줄만 사용합니다.");
+                }
+            }
+            // 첫 내용 앞의 빈 줄은 무시
+            "message" if line.is_empty() && message_lines.is_none() => {}
+            "message" => {
+                // 줄 앞의 들여쓰기와 빈 줄은 유지
+                message_lines
+                    .get_or_insert_with(|| (line_no, Vec::new()))
+                    .1
+                    .push(raw_line.trim_end().to_string());
+            }
+            "schedule" => {
+                let (key, value) = split_key_value(&line, line_no, "schedule")?;
+                let value_error = |e: String| {
+                    format!(
+                        "설정 파일 {}행: [schedule] '{}' 값 오류: {}",
+                        line_no, key, e
+                    )
+                };
+                match key.as_str() {
+                    "minutes" => builder.schedules.push((
+                        key.clone(),
+                        Schedule::parse_minutes(value).map_err(value_error)?,
+                    )),
+                    "cron" => builder.schedules.push((
+                        key.clone(),
+                        Schedule::Cron(CronExpr::parse(value).map_err(value_error)?),
+                    )),
+                    "interval" => {
+                        builder.interval =
+                            Some((line_no, parse_minutes_value(value).map_err(value_error)?))
+                    }
+                    "offset" => {
+                        builder.interval_offset = parse_minutes_value(value).map_err(value_error)?
+                    }
+                    _ => {
+                        warn_unknown_key(line_no, "schedule", &key);
+                    }
+                }
+            }
+            "grace" => {
+                let (key, value) = split_key_value(&line, line_no, "grace")?;
+                match key.as_str() {
+                    "minutes" => {
+                        builder.grace_minutes = parse_minutes_value(value).map_err(|e| {
+                            format!("설정 파일 {}행: [grace] '{}' 값 오류: {}", line_no, key, e)
+                        })? as i64
+                    }
+                    _ => {
+                        warn_unknown_key(line_no, "grace", &key);
+                    }
+                }
+            }
+            "lookback" => {
+                let (key, value) = split_key_value(&line, line_no, "lookback")?;
+                let value_error = |e: String| {
+                    format!(
+                        "설정 파일 {}행: [lookback] '{}' 값 오류: {}",
+                        line_no, key, e
+                    )
+                };
+                match key.as_str() {
+                    "since" => {
+                        builder.lookback = Lookback::parse_since(value).map_err(value_error)?
+                    }
+                    "hours" => {
+                        builder.lookback = value
+                            .parse::<u32>()
+                            .map_err(|_| format!("1 이상의 시간(정수)이어야 합니다: '{}'", value))
+                            .and_then(Lookback::hours)
+                            .map_err(value_error)?
+                    }
+                    _ => {
+                        warn_unknown_key(line_no, "lookback", &key);
+                    }
+                }
+            }
+            "formats" => {
+                let (key, value) = split_key_value(&line, line_no, "formats")?;
+                let result = match key.as_str() {
+                    "date" => builder.formats.add_date(value),
+                    "time" => builder.formats.add_time(value),
+                    "datetime" => builder.formats.add_datetime(value),
+                    _ => {
+                        warn_unknown_key(line_no, "formats", &key);
+                        Ok(())
+                    }
+                };
+                result.map_err(|e| {
+                    format!(
+                        "설정 파일 {}행: [formats] '{}' 값 오류: {}",
+                        line_no, key, e
+                    )
+                })?;
+            }
+            "completion" => {
+                let (key, value) = split_key_value(&line, line_no, "completion")?;
+                let known = builder.completion.apply(&key, value).map_err(|e| {
+                    format!(
+                        "설정 파일 {}행: [completion] '{}' 값 오류: {}",
+                        line_no, key, e
+                    )
+                })?;
+                if !known {
+                    warn_unknown_key(line_no, "completion", &key);
+                }
+            }
+            "snapshot" => {
+                let (key, value) = split_key_value(&line, line_no, "snapshot")?;
+                let value_error = |e: String| {
+                    format!(
+                        "설정 파일 {}행: [snapshot] '{}' 값 오류: {}",
+                        line_no, key, e
+                    )
+                };
+                match key.as_str() {
+                    "dir" => builder.snapshot.dir = Some(PathBuf::from(value)),
+                    "retries" => {
+                        builder.snapshot.retries = value.trim().parse::<u32>().map_err(|_| {
+                            value_error(format!("0 이상의 정수여야 합니다: '{}'", value))
+                        })?
+                    }
+                    "backoff_ms" => {
+                        builder.snapshot.backoff =
+                            Duration::from_millis(value.trim().parse::<u64>().map_err(|_| {
+                                value_error(format!(
+                                    "0 이상의 밀리초(정수)여야 합니다: '{}'",
+                                    value
+                                ))
+                            })?)
+                    }
+                    "stale_minutes" => {
+                        builder.snapshot.stale_minutes =
+                            parse_minutes_value(value).map_err(value_error)? as i64
+                    }
+                    _ => {
+                        warn_unknown_key(line_no, "snapshot", &key);
+                    }
+                }
+            }
+            "alert" => {
+                let (key, value) = split_key_value(&line, line_no, "alert")?;
+                match key.as_str() {
+                    "reminder_minutes" => {
+                        builder.reminder_minutes = parse_minutes_value(value).map_err(|e| {
+                            format!("설정 파일 {}행: [alert] '{}' 값 오류: {}", line_no, key, e)
+                        })? as i64
+                    }
+                    _ => {
+                        warn_unknown_key(line_no, "alert", &key);
+                    }
+                }
+            }
+            "tier" => {
+                let tier_name = current_name.clone().unwrap_or_default();
+                let (key, value) = split_key_value(&line, line_no, &format!("tier:{}", tier_name))?;
+                let value_error = |e: String| {
+                    format!(
+                        "설정 파일 {}행: [tier:{}] '{}' 값 오류: {}",
+                        line_no, tier_name, key, e
+                    )
+                };
+                let Some(tier) = builder.tiers.last_mut() else {
+                    continue;
+                };
+                match key.as_str() {
+                    "after_minutes" => {
+                        tier.after_minutes = parse_minutes_value(value).map_err(value_error)? as i64
+                    }
+                    "title" => tier.title = Some(Template::parse(value).map_err(value_error)?),
+                    "message" => {
+                        let (_, source) = tier_messages
+                            .entry(tier_name.clone())
+                            .or_insert_with(|| (line_no, String::new()));
+                        if !source.is_empty() {
+                            source.push('\n');
+                        }
+                        source.push_str(value);
+                    }
+                    "channels" => tier.channels = parse_channel_list(value).map_err(value_error)?,
+                    _ => {
+                        warn_unknown_key(line_no, &format!("tier:{}", tier_name), &key);
+                    }
+                }
+            }
+            "notify" => {
+                let (key, value) = split_key_value(&line, line_no, "notify")?;
+                match key.as_str() {
+                    "channels" => {
+                        builder.default_channels = parse_channel_list(value).map_err(|e| {
+                            format!("설정 파일 {}행: [notify] '{}' 값 오류: {}", line_no, key, e)
+                        })?
+                    }
+                    _ => {
+                        warn_unknown_key(line_no, "notify", &key);
+                    }
+                }
+            }
+            "notifier" => {
+                let notifier_name = current_name.clone().unwrap_or_default();
+                let (key, value) =
+                    split_key_value(&line, line_no, &format!("notifier:{}", notifier_name))?;
+                let value_error = |e: String| {
+                    format!(
+                        "설정 파일 {}행: [notifier:{}] '{}' 값 오류: {}",
+                        line_no, notifier_name, key, e
+                    )
+                };
+                if key == "type" {
+                    let kind = NotifierKind::parse(value).map_err(value_error)?;
+                    // 기본 제공 알림 방법과 이름이 같으면 교체
+                    builder.notifiers.retain(|n| n.name != notifier_name);
+                    builder.notifiers.push(NotifierConfig {
+                        name: notifier_name.clone(),
+                        kind,
+                    });
+                    typed_notifiers.push(notifier_name);
+                    continue;
+                }
+                let notifier = builder
+                    .notifiers
+                    .iter_mut()
+                    .find(|n| n.name == notifier_name)
+                    .filter(|_| typed_notifiers.contains(&notifier_name));
+                let Some(notifier) = notifier else {
+                    return Err(value_error("type 항목을 먼저 지정해야 합니다.".to_string()).into());
+                };
+                if !notifier.kind.apply(&key, value).map_err(value_error)? {
+                    warn_unknown_key(line_no, &format!("notifier:{}", notifier_name), &key);
+                }
+            }
+            "watch" => {
+                let (key, value) = split_key_value(&line, line_no, "watch")?;
+                let value_error = |e: String| {
+                    format!("설정 파일 {}행: [watch] '{}' 값 오류: {}", line_no, key, e)
+                };
+                let seconds = || {
+                    value
+                        .trim()
+                        .parse::<u64>()
+                        .map_err(|_| format!("1 이상의 초(정수)여야 합니다: '{}'", value))
+                        .and_then(positive_seconds)
+                        .map_err(value_error)
+                };
+                match key.as_str() {
+                    "enabled" => {
+                        builder.watch.enabled = match value.to_lowercase().as_str() {
+                            "true" | "yes" | "1" => true,
+                            "false" | "no" | "0" => false,
+                            _ => {
+                                return Err(value_error(format!(
+                                    "true 또는 false여야 합니다: '{}'",
+                                    value
+                                ))
+                                .into());
+                            }
+                        }
+                    }
+                    "poll_seconds" => builder.watch.poll_interval = seconds()?,
+                    "debounce_seconds" => builder.watch.debounce = seconds()?,
+                    _ => {
+                        warn_unknown_key(line_no, "watch", &key);
+                    }
+                }
+            }
+            "workbook" => {
+                let workbook_name = current_name.clone().unwrap_or_default();
+                let (key, value) =
+                    split_key_value(&line, line_no, &format!("workbook:{}", workbook_name))?;
+                let Some(settings) = builder.workbook_settings.last_mut() else {
+                    continue;
+                };
+                match key.as_str() {
+                    "path" => settings.path = Some(value.to_string()),
+                    "format" => {
+                        settings.format = Some(SourceFormat::parse(value).map_err(|e| {
+                            format!(
+                                "설정 파일 {}행: [workbook:{}] '{}' 값 오류: {}",
+                                line_no, workbook_name, key, e
+                            )
+                        })?)
+                    }
+                    "sheets" => settings.sheets.extend(
+                        value
+                            .split(',')
+                            .map(|s| s.trim().to_string())
+                            .filter(|s| !s.is_empty()),
+                    ),
+                    _ => {
+                        warn_unknown_key(line_no, &format!("workbook:{}", workbook_name), &key);
+                    }
+                }
+            }
+            "sheet" => {
+                let sheet_name = current_name.clone().unwrap_or_default();
+                let (key, value) =
+                    split_key_value(&line, line_no, &format!("sheet:{}", sheet_name))?;
+                let value_error = |e: String| {
+                    format!(
+                        "설정 파일 {}행: [sheet:{}] '{}' 값 오류: {}",
+                        line_no, sheet_name, key, e
+                    )
+                };
+                let settings = builder
+                    .sheet_settings
+                    .entry(sheet_name.clone())
+                    .or_insert_with(|| SheetConfig::new(sheet_name.clone()));
+                match key.as_str() {
+                    "date" | "time" | "datetime" | "done" | "description" => {
+                        let column = Some(ColumnRef::parse(value).map_err(value_error)?);
+                        match key.as_str() {
+                            "date" => settings.columns.date = column,
+                            "time" => settings.columns.time = column,
+                            "datetime" => settings.columns.datetime = column,
+                            "done" => settings.columns.done = column,
+                            _ => settings.columns.description = column,
+                        }
+                    }
+                    "header_row" => {
+                        settings.header_row = parse_header_row(value).map_err(value_error)?;
+                    }
+                    "grace" => {
+                        settings.grace_minutes =
+                            Some(parse_minutes_value(value).map_err(value_error)? as i64);
+                    }
+                    _ => {
+                        warn_unknown_key(line_no, &format!("sheet:{}", sheet_name), &key);
+                    }
+                }
+            }
+            _ => {} // 알 수 없는 섹션은 읽을 때 경고
+        }
+    }
+
+    // 이전 형식의 [target_path] + [manage_game]은 워크북 하나로 변환
+    match (excel_path_str, manage_games.is_empty()) {
+        (Some(path), _) => {
+            let name = Path::new(&path)
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.clone());
+            if builder.workbook_settings.iter().any(|w| w.name == name) {
+                return Err(format!(
+                    "[target_path] 파일 이름 '{}'이 [workbook:{}] 이름과 겹칩니다.",
+                    name, name
+                )
+                .into());
+            }
+            builder.workbook_settings.insert(
+                0,
+                WorkbookSection {
+                    name,
+                    path: Some(path),
+                    format: None,
+                    sheets: manage_games,
+                },
+            );
+        }
+        (None, false) => {
+            warn!("[manage_game]이 있지만 [target_path]가 없습니다. [manage_game]을 무시합니다.");
+        }
+        (None, true) => {}
+    }
+
+    for name in &defined_notifiers {
+        if !typed_notifiers.contains(name) {
+            return Err(format!("[notifier:{}]에 type 항목이 없습니다.", name).into());
+        }
+    }
+    if let Some((line_no, mut lines)) = message_lines {
+        // 다음 섹션 앞의 빈 줄은 템플릿에 넣지 않음
+        while lines.last().is_some_and(|l| l.is_empty()) {
+            lines.pop();
+        }
+        builder.notification_message_template = Some(
+            Template::parse(&lines.join("\n"))
+                .map_err(|e| format!("설정 파일 {}행: [message] 템플릿 오류: {}", line_no, e))?,
+        );
+    }
+    for tier in &mut builder.tiers {
+        if let Some((line_no, source)) = tier_messages.remove(&tier.name) {
+            tier.message = Some(Template::parse(&source).map_err(|e| {
+                format!(
+                    "설정 파일 {}행: [tier:{}] 'message' 템플릿 오류: {}",
+                    line_no, tier.name, e
+                )
+            })?);
+        }
+    }
+    Ok(builder)
+}
+
+/// TOML 형식 설정. 스키마에 없는 섹션/항목이나 잘못된 값 종류는 행 번호와 함께 오류로 보고합니다.
+///
+/// ```toml
+/// title = "알림"
+/// message = """
+/// {count}개의 누락된 데이터가 존재합니다!
+/// """
+///
+/// [schedule]
+/// minutes = [11, 26, 41, 56]
+///
+/// [[workbook]]
+/// name = "점검"
+/// path = "C:/data/점검.xlsx"
+/// sheets = ["1호기", "2호기"]
+///
+/// [sheet."1호기"]
+/// date = "B"
+///
+/// [[tier]]
+/// name = "긴급"
+/// after_minutes = 60
+/// channels = ["teams"]
+///
+/// [notifier.teams]
+/// type = "teams"
+/// url = "https://..."
+/// ```
+pub fn parse_toml(source: &str) -> Result<ConfigBuilder, Box<dyn Error>> {
+    let file: ConfigFile = toml::from_str(source).map_err(|e| {
+        let position = e
+            .span()
+            .map(|span| {
+                let (line, column) = line_column(source, span.start);
+                format!("{}행 {}열", line, column)
+            })
+            .unwrap_or_default();
+        format!(
+            "설정 파일 {}: {}",
+            position,
+            e.message().trim().replace('\n', ", ")
+        )
+    })?;
+    Ok(file.into_builder(source)?)
+}
+
+// 바이트 위치의 (행, 열). 모두 1부터
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .map_or(0, |text| text.chars().count())
+        + 1;
+    (line, column)
+}
+
+/// 값 하나 또는 값 목록. `channels = "teams"`와 `channels = ["teams", "email"]` 모두 허용
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    fn items(&self) -> &[T] {
+        match self {
+            OneOrMany::One(value) => slice::from_ref(value),
+            OneOrMany::Many(values) => values,
+        }
+    }
+}
+
+/// 숫자 또는 문자열 (`header_row = 2`, `header_row = "auto"`)
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum NumberOrText {
+    Number(i64),
+    Text(String),
+}
+
+type Field<T> = Option<Spanned<T>>;
+type ListField<T> = Option<Spanned<OneOrMany<T>>>;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    title: Field<String>,
+    message: Field<String>,
+    schedule: Option<Spanned<ScheduleTable>>,
+    grace: Option<Spanned<GraceTable>>,
+    lookback: Option<Spanned<LookbackTable>>,
+    formats: Option<Spanned<FormatsTable>>,
+    completion: Option<Spanned<CompletionTable>>,
+    snapshot: Option<Spanned<SnapshotTable>>,
+    alert: Option<Spanned<AlertTable>>,
+    notify: Option<Spanned<NotifyTable>>,
+    watch: Option<Spanned<WatchTable>>,
+    #[serde(default)]
+    workbook: Vec<Spanned<WorkbookTable>>,
+    #[serde(default)]
+    sheet: BTreeMap<String, Spanned<SheetTable>>,
+    #[serde(default)]
+    tier: Vec<Spanned<TierTable>>,
+    #[serde(default)]
+    notifier: BTreeMap<String, Spanned<NotifierTable>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScheduleTable {
+    minutes: ListField<u32>,
+    cron: Field<String>,
+    interval: Field<u32>,
+    offset: Field<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GraceTable {
+    minutes: Field<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LookbackTable {
+    since: Field<String>,
+    hours: Field<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FormatsTable {
+    date: ListField<String>,
+    time: ListField<String>,
+    datetime: ListField<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CompletionTable {
+    done: ListField<String>,
+    skip: ListField<String>,
+    pending: ListField<String>,
+    done_pattern: Field<String>,
+    skip_pattern: Field<String>,
+    any_value: Field<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SnapshotTable {
+    dir: Field<String>,
+    retries: Field<u32>,
+    backoff_ms: Field<u64>,
+    stale_minutes: Field<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AlertTable {
+    reminder_minutes: Field<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NotifyTable {
+    channels: ListField<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WatchTable {
+    enabled: Field<bool>,
+    poll_seconds: Field<u64>,
+    debounce_seconds: Field<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WorkbookTable {
+    name: String,
+    path: Field<String>,
+    format: Field<String>,
+    sheets: ListField<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SheetTable {
+    date: Field<String>,
+    time: Field<String>,
+    datetime: Field<String>,
+    done: Field<String>,
+    description: Field<String>,
+    header_row: Field<NumberOrText>,
+    grace: Field<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TierTable {
+    name: String,
+    after_minutes: Field<u32>,
+    title: Field<String>,
+    message: Field<String>,
+    channels: ListField<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NotifierTable {
+    #[serde(rename = "type")]
+    kind: Spanned<String>,
+    path: Field<String>,
+    command: Field<String>,
+    #[serde(alias = "arg")]
+    args: Field<OneOrMany<String>>,
+    url: Field<String>,
+    card: Field<String>,
+    #[serde(alias = "header")]
+    headers: Field<BTreeMap<String, String>>,
+    body: Field<String>,
+    timeout_seconds: Field<u64>,
+    retries: Field<u32>,
+    backoff_ms: Field<u64>,
+    host: Field<String>,
+    port: Field<u16>,
+    security: Field<String>,
+    username: Field<String>,
+    password: Field<String>,
+    from: Field<String>,
+    to: ListField<String>,
+    digest_at: Field<String>,
+}
+
+// TOML 값의 행 번호로 오류 메시지를 만듦
+struct Lines<'a> {
+    source: &'a str,
+}
+
+impl Lines<'_> {
+    fn of<T>(&self, value: &Spanned<T>) -> usize {
+        line_column(self.source, value.span().start).0
+    }
+
+    // "설정 파일 3행: [schedule] 'minutes' 값 오류: ..." 형식으로 바꾸는 함수
+    fn value_error<'s, T>(
+        &self,
+        section: &'s str,
+        key: &'s str,
+        value: &Spanned<T>,
+    ) -> impl Fn(String) -> String + 's {
+        let line = self.of(value);
+        move |e| {
+            format!(
+                "설정 파일 {}행: [{}] '{}' 값 오류: {}",
+                line, section, key, e
+            )
+        }
+    }
+
+    fn template(&self, name: &str, value: &Spanned<String>) -> Result<Template, String> {
+        Template::parse(value.get_ref()).map_err(|e| {
+            format!(
+                "설정 파일 {}행: {} 템플릿 오류: {}",
+                self.of(value),
+                name,
+                e
+            )
+        })
+    }
+}
+
+impl ConfigFile {
+    // 표마다 INI와 같은 값 확인을 거쳐 설정에 채움. 목록과 여러 줄 문자열은 그대로 사용
+    fn into_builder(self, source: &str) -> Result<ConfigBuilder, String> {
+        let lines = Lines { source };
+        let mut builder = ConfigBuilder::default();
+
+        if let Some(title) = &self.title {
+            builder.notification_title = Some(lines.template("'title'", title)?);
+        }
+        if let Some(message) = &self.message {
+            builder.notification_message_template = Some(lines.template("'message'", message)?);
+        }
+
+        if let Some(table) = &self.schedule {
+            let t = table.get_ref();
+            if let Some(minutes) = &t.minutes {
+                let schedule = Schedule::minutes(minutes.get_ref().items().to_vec())
+                    .map_err(lines.value_error("schedule", "minutes", minutes))?;
+                builder.schedules.push(("minutes".to_string(), schedule));
+            }
+            if let Some(cron) = &t.cron {
+                let expr = CronExpr::parse(cron.get_ref())
+                    .map_err(lines.value_error("schedule", "cron", cron))?;
+                builder
+                    .schedules
+                    .push(("cron".to_string(), Schedule::Cron(expr)));
+            }
+            if let Some(interval) = &t.interval {
+                builder.interval = Some((lines.of(interval), *interval.get_ref()));
+            }
+            if let Some(offset) = &t.offset {
+                builder.interval_offset = *offset.get_ref();
+            }
+        }
+        if let Some(minutes) = self
+            .grace
+            .as_ref()
+            .and_then(|t| t.get_ref().minutes.as_ref())
+        {
+            builder.grace_minutes = *minutes.get_ref() as i64;
+        }
+        if let Some(table) = &self.lookback {
+            let t = table.get_ref();
+            if let Some(since) = &t.since {
+                builder.lookback = Lookback::parse_since(since.get_ref())
+                    .map_err(lines.value_error("lookback", "since", since))?;
+            }
+            if let Some(hours) = &t.hours {
+                builder.lookback = Lookback::hours(*hours.get_ref())
+                    .map_err(lines.value_error("lookback", "hours", hours))?;
+            }
+        }
+        if let Some(table) = &self.formats {
+            let t = table.get_ref();
+            let formats = &mut builder.formats;
+            for (key, list) in [
+                ("date", &t.date),
+                ("time", &t.time),
+                ("datetime", &t.datetime),
+            ] {
+                let Some(list) = list else { continue };
+                for format in list.get_ref().items() {
+                    match key {
+                        "date" => formats.add_date(format),
+                        "time" => formats.add_time(format),
+                        _ => formats.add_datetime(format),
+                    }
+                    .map_err(lines.value_error("formats", key, list))?;
+                }
+            }
+        }
+        if let Some(table) = &self.completion {
+            let t = table.get_ref();
+            let completion = &mut builder.completion;
+            for (key, list) in [
+                ("done", &t.done),
+                ("skip", &t.skip),
+                ("pending", &t.pending),
+            ] {
+                if let Some(list) = list {
+                    let values: Vec<&str> =
+                        list.get_ref().items().iter().map(String::as_str).collect();
+                    completion.set_values(key, &values);
+                }
+            }
+            for (key, pattern) in [
+                ("done_pattern", &t.done_pattern),
+                ("skip_pattern", &t.skip_pattern),
+            ] {
+                if let Some(pattern) = pattern {
+                    completion
+                        .apply(key, pattern.get_ref())
+                        .map_err(lines.value_error("completion", key, pattern))?;
+                }
+            }
+            if let Some(any_value) = &t.any_value {
+                completion.any_value = *any_value.get_ref();
+            }
+        }
+        if let Some(table) = &self.snapshot {
+            let t = table.get_ref();
+            let snapshot = &mut builder.snapshot;
+            if let Some(dir) = &t.dir {
+                snapshot.dir = Some(PathBuf::from(dir.get_ref()));
+            }
+            if let Some(retries) = &t.retries {
+                snapshot.retries = *retries.get_ref();
+            }
+            if let Some(backoff_ms) = &t.backoff_ms {
+                snapshot.backoff = Duration::from_millis(*backoff_ms.get_ref());
+            }
+            if let Some(stale_minutes) = &t.stale_minutes {
+                snapshot.stale_minutes = *stale_minutes.get_ref() as i64;
+            }
+        }
+        if let Some(minutes) = self
+            .alert
+            .as_ref()
+            .and_then(|t| t.get_ref().reminder_minutes.as_ref())
+        {
+            builder.reminder_minutes = *minutes.get_ref() as i64;
+        }
+        if let Some(channels) = self
+            .notify
+            .as_ref()
+            .and_then(|t| t.get_ref().channels.as_ref())
+        {
+            builder.default_channels =
+                channel_list(channels.get_ref().items().iter().map(String::as_str))
+                    .map_err(lines.value_error("notify", "channels", channels))?;
+        }
+        if let Some(table) = &self.watch {
+            let t = table.get_ref();
+            let watch = &mut builder.watch;
+            if let Some(enabled) = &t.enabled {
+                watch.enabled = *enabled.get_ref();
+            }
+            if let Some(seconds) = &t.poll_seconds {
+                watch.poll_interval = positive_seconds(*seconds.get_ref())
+                    .map_err(lines.value_error("watch", "poll_seconds", seconds))?;
+            }
+            if let Some(seconds) = &t.debounce_seconds {
+                watch.debounce = positive_seconds(*seconds.get_ref())
+                    .map_err(lines.value_error("watch", "debounce_seconds", seconds))?;
+            }
+        }
+
+        for table in &self.workbook {
+            let t = table.get_ref();
+            let section = format!("workbook.{}", t.name);
+            if builder.workbook_settings.iter().any(|w| w.name == t.name) {
+                return Err(format!(
+                    "설정 파일 {}행: [[workbook]] 이름 '{}'이(가) 중복되었습니다.",
+                    lines.of(table),
+                    t.name
+                ));
+            }
+            let format = match &t.format {
+                Some(format) => Some(
+                    SourceFormat::parse(format.get_ref())
+                        .map_err(lines.value_error(&section, "format", format))?,
+                ),
+                None => None,
+            };
+            // 목록의 각 항목이 시트 이름 하나 (이름에 쉼표가 있어도 나누지 않음)
+            let sheets = t.sheets.as_ref().map_or_else(Vec::new, |sheets| {
+                sheets
+                    .get_ref()
+                    .items()
+                    .iter()
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            });
+            builder.workbook_settings.push(WorkbookSection {
+                name: t.name.clone(),
+                path: t.path.as_ref().map(|p| p.get_ref().clone()),
+                format,
+                sheets,
+            });
+        }
+
+        for (name, table) in &self.sheet {
+            let t = table.get_ref();
+            let section = format!("sheet.{}", name);
+            let mut settings = SheetConfig::new(name.clone());
+            let columns = &mut settings.columns;
+            let fields = [
+                ("date", &t.date, &mut columns.date),
+                ("time", &t.time, &mut columns.time),
+                ("datetime", &t.datetime, &mut columns.datetime),
+                ("done", &t.done, &mut columns.done),
+                ("description", &t.description, &mut columns.description),
+            ];
+            for (key, value, column) in fields {
+                if let Some(value) = value {
+                    *column = Some(
+                        ColumnRef::parse(value.get_ref())
+                            .map_err(lines.value_error(&section, key, value))?,
+                    );
+                }
+            }
+            if let Some(header_row) = &t.header_row {
+                settings.header_row = match header_row.get_ref() {
+                    NumberOrText::Number(row) => header_row_number(*row),
+                    NumberOrText::Text(text) => parse_header_row(text),
+                }
+                .map_err(lines.value_error(&section, "header_row", header_row))?;
+            }
+            if let Some(grace) = &t.grace {
+                settings.grace_minutes = Some(*grace.get_ref() as i64);
+            }
+            builder.sheet_settings.insert(name.clone(), settings);
+        }
+
+        for table in &self.tier {
+            let t = table.get_ref();
+            let section = format!("tier.{}", t.name);
+            if builder.tiers.iter().any(|tier| tier.name == t.name) {
+                return Err(format!(
+                    "설정 파일 {}행: [[tier]] 이름 '{}'이(가) 중복되었습니다.",
+                    lines.of(table),
+                    t.name
+                ));
+            }
+            let mut tier = EscalationTier::new(t.name.clone());
+            if let Some(after_minutes) = &t.after_minutes {
+                tier.after_minutes = *after_minutes.get_ref() as i64;
+            }
+            if let Some(title) = &t.title {
+                tier.title = Some(lines.template(&format!("[{}] 'title'", section), title)?);
+            }
+            // 여러 줄 메시지는 줄 앞의 들여쓰기까지 그대로 사용
+            if let Some(message) = &t.message {
+                tier.message = Some(lines.template(&format!("[{}] 'message'", section), message)?);
+            }
+            if let Some(channels) = &t.channels {
+                tier.channels = channel_list(channels.get_ref().items().iter().map(String::as_str))
+                    .map_err(lines.value_error(&section, "channels", channels))?;
+            }
+            builder.tiers.push(tier);
+        }
+
+        for (name, table) in &self.notifier {
+            let t = table.get_ref();
+            let section = format!("notifier.{}", name);
+            let mut kind = NotifierKind::parse(t.kind.get_ref())
+                .map_err(lines.value_error(&section, "type", &t.kind))?;
+            // 종류에 맞지 않는 항목은 INI와 같이 경고 후 무시
+            let mut apply = |key: &str, value: &str, line: usize| -> Result<(), String> {
+                if !kind.apply(key, value).map_err(|e| {
+                    format!(
+                        "설정 파일 {}행: [{}] '{}' 값 오류: {}",
+                        line, section, key, e
+                    )
+                })? {
+                    warn!(
+                        "설정 파일 {}행: [{}]의 '{}' 항목은 이 알림 종류에서 쓰지 않으므로 무시",
+                        line, section, key
+                    );
+                }
+                Ok(())
+            };
+            let texts = [
+                ("path", &t.path),
+                ("command", &t.command),
+                ("url", &t.url),
+                ("card", &t.card),
+                ("body", &t.body),
+                ("host", &t.host),
+                ("security", &t.security),
+                ("username", &t.username),
+                ("password", &t.password),
+                ("from", &t.from),
+                ("digest_at", &t.digest_at),
+            ];
+            for (key, value) in texts {
+                if let Some(value) = value {
+                    apply(key, value.get_ref(), lines.of(value))?;
+                }
+            }
+            let numbers = [
+                (
+                    "timeout_seconds",
+                    t.timeout_seconds
+                        .as_ref()
+                        .map(|v| (lines.of(v), *v.get_ref())),
+                ),
+                (
+                    "retries",
+                    t.retries
+                        .as_ref()
+                        .map(|v| (lines.of(v), u64::from(*v.get_ref()))),
+                ),
+                (
+                    "backoff_ms",
+                    t.backoff_ms.as_ref().map(|v| (lines.of(v), *v.get_ref())),
+                ),
+                (
+                    "port",
+                    t.port
+                        .as_ref()
+                        .map(|v| (lines.of(v), u64::from(*v.get_ref()))),
+                ),
+            ];
+            for (key, value) in numbers {
+                if let Some((line, value)) = value {
+                    apply(key, &value.to_string(), line)?;
+                }
+            }
+            if let Some(args) = &t.args {
+                for arg in args.get_ref().items() {
+                    apply("arg", arg, lines.of(args))?;
+                }
+            }
+            if let Some(to) = &t.to {
+                for address in to.get_ref().items() {
+                    apply("to", address, lines.of(to))?;
+                }
+            }
+            if let Some(headers) = &t.headers {
+                for (header, value) in headers.get_ref() {
+                    let known = kind
+                        .add_header(header, value)
+                        .map_err(lines.value_error(&section, "headers", headers))?;
+                    if !known {
+                        warn!(
+                            "설정 파일 {}행: [{}]의 'headers' 항목은 이 알림 종류에서 쓰지 않으므로 무시",
+                            lines.of(headers),
+                            section
+                        );
+                        break;
+                    }
+                }
+            }
+            // 기본 제공 알림 방법과 이름이 같으면 교체
+            builder.notifiers.retain(|n| n.name != *name);
+            builder.notifiers.push(NotifierConfig {
+                name: name.clone(),
+                kind,
+            });
+        }
+
+        Ok(builder)
+    }
+}
+
+/// 설정 파일에서 읽은 값. INI와 TOML 모두 항목 값을 확인해 여기에 채우고,
+/// 워크북과 시트 설정 연결, 실행 일정, 알림 단계처럼 여러 항목에 걸친 확인은 `build`에서 합니다.
+pub struct ConfigBuilder {
+    pub notification_title: Option<Template>,
+    pub notification_message_template: Option<Template>,
+    /// 설정 파일에 나온 순서 유지
+    pub workbook_settings: Vec<WorkbookSection>,
+    /// `<시트>` 또는 `<워크북>/<시트>` 이름별 시트 설정
+    pub sheet_settings: HashMap<String, SheetConfig>,
+    /// (항목 이름, 일정). 둘 이상 지정하면 오류
+    pub schedules: Vec<(String, Schedule)>,
+    /// (행 번호, 간격 분). `interval_offset`과 함께 일정으로 만듦
+    pub interval: Option<(usize, u32)>,
+    pub interval_offset: u32,
+    pub grace_minutes: i64,
+    pub lookback: Lookback,
+    pub formats: DateTimeFormats,
+    pub completion: CompletionRules,
+    pub snapshot: SnapshotSettings,
+    pub watch: WatchSettings,
+    pub reminder_minutes: i64,
+    pub tiers: Vec<EscalationTier>,
+    /// 기본 제공 알림 방법과 설정 파일에 정의한 알림 방법
+    pub notifiers: Vec<NotifierConfig>,
+    pub default_channels: Vec<String>,
+}
+
+impl Default for ConfigBuilder {
+    fn default() -> Self {
+        ConfigBuilder {
+            notification_title: None,
+            notification_message_template: None,
+            workbook_settings: Vec::new(),
+            sheet_settings: HashMap::new(),
+            schedules: Vec::new(),
+            interval: None,
+            interval_offset: 0,
+            grace_minutes: DEFAULT_GRACE_MINUTES,
+            lookback: Lookback::default(),
+            formats: DateTimeFormats::default(),
+            completion: CompletionRules::default(),
+            snapshot: SnapshotSettings::default(),
+            watch: WatchSettings::default(),
+            reminder_minutes: DEFAULT_REMINDER_MINUTES,
+            tiers: Vec::new(),
+            notifiers: builtin_notifiers(),
+            default_channels: vec![TOAST_NOTIFIER.to_string()],
+        }
+    }
+}
+
+impl ConfigBuilder {
+    /// 여러 항목에 걸친 설정을 확인하고 `Config`를 만듭니다.
+    pub fn build(self) -> Result<Config, Box<dyn Error>> {
+        let ConfigBuilder {
+            notification_title,
+            notification_message_template,
+            workbook_settings,
+            sheet_settings,
+            mut schedules,
+            interval,
+            interval_offset,
+            grace_minutes,
+            lookback,
+            formats,
+            completion,
+            snapshot,
+            watch,
+            reminder_minutes,
+            tiers,
+            notifiers,
+            default_channels,
+        } = self;
+
+        if workbook_settings.is_empty() {
+            error!("설정 파일에 [target_path] 또는 [workbook:<이름>] 섹션이 없습니다.");
+            return Err("설정 파일에 [target_path] 또는 [workbook:<이름>] 섹션이 없습니다.".into());
+        }
+
+        let mut used_sheet_settings = Vec::new();
+        let mut workbooks = Vec::new();
+        for WorkbookSection {
+            name,
+            path,
+            format,
+            sheets: mut sheet_names,
+        } in workbook_settings
+        {
+            let Some(path) = path.map(PathBuf::from) else {
+                return Err(format!("[workbook:{}]에 path가 없습니다.", name).into());
+            };
+            let is_csv =
+                format.or_else(|| SourceFormat::from_path(&path)) == Some(SourceFormat::Csv);
+            if sheet_names.is_empty()
+                && is_csv
+                && let Some(stem) = path.file_stem()
+            {
+                sheet_names.push(stem.to_string_lossy().into_owned());
+            }
+            if sheet_names.is_empty() {
+                warn!("워크북 '{}'에 관리할 시트 이름이 없습니다.", name);
+                return Err(format!("워크북 '{}'에 관리할 시트 이름이 없습니다.", name).into());
+            }
+
+            // [sheet:<워크북>/<시트>]가 있으면 [sheet:<시트>]보다 우선
+            let sheets = sheet_names
+                .into_iter()
+                .map(|sheet_name| {
+                    let specific_key = format!("{}/{}", name, sheet_name);
+                    let key = if sheet_settings.contains_key(&specific_key) {
+                        specific_key
+                    } else {
+                        sheet_name.clone()
+                    };
+                    used_sheet_settings.push(key.clone());
+                    match sheet_settings.get(&key) {
+                        Some(settings) => SheetConfig {
+                            name: sheet_name,
+                            ..settings.clone()
+                        },
+                        None => SheetConfig::new(sheet_name),
+                    }
+                })
+                .collect();
+
+            workbooks.push(WorkbookConfig {
+                name,
+                path,
+                format,
+                sheets,
+            });
+        }
+
+        for key in sheet_settings.keys() {
+            if !used_sheet_settings.contains(key) {
+                warn!(
+                    "[sheet:{}] 설정이 있지만 관리 대상으로 등록되지 않은 시트입니다. 무시합니다.",
+                    key
+                );
+            }
+        }
+
+        if let Some((line_no, minutes)) = interval {
+            let schedule = Schedule::interval(minutes, interval_offset).map_err(|e| {
+                format!(
+                    "설정 파일 {}행: [schedule] 'interval' 값 오류: {}",
+                    line_no, e
+                )
+            })?;
+            schedules.push(("interval".to_string(), schedule));
+        }
+        if schedules.len() > 1 {
+            let keys: Vec<&str> = schedules.iter().map(|(key, _)| key.as_str()).collect();
+            return Err(format!(
+                "[schedule]에는 minutes, interval, cron 중 하나만 지정해야 합니다: {}",
+                keys.join(", ")
+            )
+            .into());
+        }
+        let schedule = schedules.pop().map(|(_, s)| s).unwrap_or_default();
+
+        for notifier in &notifiers {
+            notifier
+                .kind
+                .validate()
+                .map_err(|e| format!("[notifier:{}] 설정 오류: {}", notifier.name, e))?;
+        }
+
+        let notification_title = match notification_title {
+            Some(title) => title,
+            None => Template::parse(DEFAULT_TITLE)?,
+        };
+        let notification_message_template = match notification_message_template {
+            Some(message) => message,
+            None => Template::parse(DEFAULT_MESSAGE)?,
+        };
+
+        let escalation = EscalationPolicy::new(tiers, &default_channels)?;
+        for tier in escalation.tiers() {
+            if let Some(unknown) = tier
+                .channels
+                .iter()
+                .find(|c| !notifiers.iter().any(|n| n.name == **c))
+            {
+                return Err(format!(
+                    "[tier:{}]의 알림 채널 '{}'이(가) 정의되지 않았습니다.",
+                    tier.name, unknown
+                )
+                .into());
+            }
+        }
+
+        Ok(Config {
+            workbooks,
+            notification_title,
+            notification_message_template,
+            schedule,
+            grace_minutes,
+            lookback,
+            formats,
+            completion,
+            snapshot,
+            watch,
+            reminder_minutes,
+            escalation,
+            notifiers,
+        })
+    }
+}
+
+// "key = value" 형식의 설정 줄을 (소문자 key, value)로 분리
+fn split_key_value<'a>(
+    line: &'a str,
+    line_no: usize,
+    section: &str,
+) -> Result<(String, &'a str), String> {
+    let (key, value) = line.split_once('=').ok_or_else(|| {
+        format!(
+            "설정 파일 {}행: [{}] 항목은 'key = value' 형식이어야 합니다: {}",
+            line_no, section, line
+        )
+    })?;
+    Ok((key.trim().to_lowercase(), value.trim()))
+}
+
+/// `header_row` 값. 1 이상의 행 번호, 또는 자동으로 판단하는 auto(None).
+pub fn parse_header_row(value: &str) -> Result<Option<usize>, String> {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("auto") {
+        return Ok(None);
+    }
+    value
+        .parse::<i64>()
+        .map_err(|_| format!("1 이상의 행 번호 또는 auto 여야 합니다: {}", value))
+        .and_then(header_row_number)
+}
+
+/// 숫자로 지정한 `header_row` 값. 1 이상이어야 합니다.
+pub fn header_row_number(row: i64) -> Result<Option<usize>, String> {
+    usize::try_from(row)
+        .ok()
+        .filter(|row| *row >= 1)
+        .map(Some)
+        .ok_or_else(|| format!("1 이상의 행 번호 또는 auto 여야 합니다: {}", row))
+}
+
+/// `[watch]`의 초 값. 1 이상이어야 합니다.
+pub fn positive_seconds(seconds: u64) -> Result<Duration, String> {
+    if seconds == 0 {
+        return Err("1 이상의 초(정수)여야 합니다: '0'".to_string());
+    }
+    Ok(Duration::from_secs(seconds))
+}
+
+// 0 이상의 분 값 파싱
+fn parse_minutes_value(value: &str) -> Result<u32, String> {
+    value
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| format!("0 이상의 분(정수)이어야 합니다: '{}'", value.trim()))
+}
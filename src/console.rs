@@ -0,0 +1,95 @@
+// src/console.rs
+use std::{
+    io::{self, BufRead},
+    thread,
+};
+
+use tokio::sync::mpsc::{self, Receiver, Sender};
+use tracing::{info, warn};
+
+use crate::utils::set_log_level;
+
+/// 콘솔에서 입력받아 서비스 루프로 전달하는 명령.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsoleCommand {
+    Quit,
+    Check,
+    Status,
+}
+
+pub const HELP_TEXT: &str = "\
+사용 가능한 명령:
+  check         즉시 누락 항목 검사 실행
+  status        마지막 실행 시각, 다음 실행 예정 시각, 마지막 누락 개수 출력
+  log <level>   로그 레벨 변경 (예: log debug, log info, log checker=trace)
+  help          도움말 출력
+  quit          서비스 종료";
+
+/// 표준 입력을 읽는 콘솔 스레드를 띄우고, 명령 수신 채널을 반환합니다.
+///
+/// stdin 읽기는 취소할 수 없는 블로킹 호출이라 tokio 런타임 대신 별도 스레드에서 처리합니다.
+/// help/log 명령은 콘솔 스레드에서 바로 처리하고, 나머지는 채널로 넘깁니다.
+pub fn spawn_console() -> io::Result<Receiver<ConsoleCommand>> {
+    let (tx, rx) = mpsc::channel(8);
+
+    thread::Builder::new()
+        .name("console".to_string())
+        .spawn(move || run_console(tx))?;
+
+    Ok(rx)
+}
+
+fn run_console(tx: Sender<ConsoleCommand>) {
+    let stdin = io::stdin();
+    for line in stdin.lock().lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                warn!("콘솔 입력 읽기 실패: {}", e);
+                break;
+            }
+        };
+
+        let mut parts = line.split_whitespace();
+        let Some(command) = parts.next() else {
+            continue;
+        };
+
+        let console_command = match command.to_lowercase().as_str() {
+            "quit" | "exit" => ConsoleCommand::Quit,
+            "check" => ConsoleCommand::Check,
+            "status" => ConsoleCommand::Status,
+            "help" | "?" => {
+                println!("{}", HELP_TEXT);
+                continue;
+            }
+            "log" => {
+                match parts.next() {
+                    Some(level) => match set_log_level(level) {
+                        Ok(_) => info!("로그 레벨 변경: {}", level),
+                        Err(e) => println!("로그 레벨 변경 실패 '{}': {}", level, e),
+                    },
+                    None => println!("사용법: log <level> (trace, debug, info, warn, error)"),
+                }
+                continue;
+            }
+            other => {
+                println!(
+                    "알 수 없는 명령: '{}'. 'help'로 사용 가능한 명령을 확인하세요.",
+                    other
+                );
+                continue;
+            }
+        };
+
+        let is_quit = console_command == ConsoleCommand::Quit;
+        if tx.blocking_send(console_command).is_err() {
+            // 서비스 루프가 이미 종료됨
+            break;
+        }
+        if is_quit {
+            break;
+        }
+    }
+    info!("콘솔 입력이 종료되었습니다. 명령 입력을 더 이상 받지 않습니다.");
+}
@@ -1,3 +1,85 @@
 pub const CONFIG_FILE_NAME: &str = "config.cfg";
+// 있으면 config.cfg 대신 사용
+pub const CONFIG_TOML_FILE_NAME: &str = "config.toml";
 pub const OUTPUT_FILE_NAME: &str = "missing.txt";
+pub const ALERT_STATE_FILE_NAME: &str = "alert_state.json";
+pub const NOTIFICATION_EXE_NAME: &str = "notification.exe";
+
+// check-once, report 명령의 종료 코드. 누락 항목이 없으면 0
+pub const EXIT_MISSING: u8 = 1;
+pub const EXIT_ERROR: u8 = 2;
+
+// 기본 제공 알림 방법 이름
+pub const TOAST_NOTIFIER: &str = "toast";
+pub const CONSOLE_NOTIFIER: &str = "console";
+
+// 네트워크 알림(Teams, 웹후크) 기본 재시도 설정
+pub const DEFAULT_NOTIFY_RETRIES: u32 = 3;
+pub const DEFAULT_NOTIFY_BACKOFF_MS: u64 = 1000;
+pub const DEFAULT_HTTP_TIMEOUT_SECS: u64 = 10;
+// Teams 카드에 표시하는 최대 누락 항목 수. 나머지는 개수만 표시
+pub const MAX_CARD_ENTRIES: usize = 30;
+
+// 메일 일일 요약. 모은 항목은 실행 파일 폴더의 digest_<알림 이름>.json에 저장
+pub const DIGEST_FILE_PREFIX: &str = "digest_";
+pub const DIGEST_TITLE: &str = "누락 항목 일일 요약 ({date})";
+pub const DIGEST_MESSAGE: &str = "{date} 하루 동안 누락된 항목 {count}개입니다.";
+
 pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+// 문자열 날짜/시간 셀의 기본 해석 형식 (config의 [formats]로 추가 가능)
+pub const DEFAULT_DATE_FORMATS: &[&str] = &[
+    DATE_FORMAT,
+    "%Y/%m/%d",
+    "%Y.%m.%d",
+    "%Y. %m. %d.",
+    "%Y년 %m월 %d일",
+    "%m월 %d일",
+];
+pub const DEFAULT_TIME_FORMATS: &[&str] = &[
+    "%H:%M:%S",
+    "%H:%M",
+    "%I:%M:%S %p",
+    "%I:%M %p",
+    "%p %I:%M:%S",
+    "%p %I:%M",
+    "%H시 %M분",
+    "%p %I시 %M분",
+];
+pub const DEFAULT_DATETIME_FORMATS: &[&str] = &["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"];
+
+// 완료 열 값의 기본 분류 (config의 [completion]으로 변경 가능)
+pub const DEFAULT_DONE_VALUES: &[&str] = &["완료", "o", "y", "yes", "true", "done", "v", "✓", "✔"];
+pub const DEFAULT_SKIP_VALUES: &[&str] =
+    &["취소", "생략", "cancelled", "canceled", "skipped", "skip"];
+pub const DEFAULT_PENDING_VALUES: &[&str] =
+    &["보류", "대기", "미완료", "pending", "n", "no", "false", "x"];
+
+// 헤더 행에서 열을 찾을 때 사용하는 기본 헤더 이름
+pub const DATE_HEADERS: &[&str] = &["날짜", "일자", "date"];
+pub const TIME_HEADERS: &[&str] = &["시간", "시각", "time"];
+pub const DATETIME_HEADERS: &[&str] = &["일시", "날짜 시간", "datetime"];
+pub const DONE_HEADERS: &[&str] = &["완료", "완료 여부", "done"];
+pub const DESCRIPTION_HEADERS: &[&str] = &["내용", "설명", "description"];
+
+// 예정 시각 이후 누락으로 판단하기까지의 기본 유예 시간(분)
+pub const DEFAULT_GRACE_MINUTES: i64 = 9;
+
+// 원본 파일 읽기 재시도와 스냅샷 기본값
+pub const DEFAULT_SNAPSHOT_RETRIES: u32 = 3;
+pub const DEFAULT_SNAPSHOT_BACKOFF_MS: u64 = 500;
+pub const DEFAULT_STALE_MINUTES: i64 = 30;
+
+// 파일 변경 감지 기본값
+pub const DEFAULT_WATCH_POLL_SECS: u64 = 2;
+pub const DEFAULT_WATCH_DEBOUNCE_SECS: u64 = 5;
+
+// 같은 누락 항목을 다시 알리는 기본 주기 (분)
+pub const DEFAULT_REMINDER_MINUTES: i64 = 60;
+
+// [title], [message]를 지정하지 않았을 때의 알림 템플릿
+pub const DEFAULT_TITLE: &str = "알림";
+pub const DEFAULT_MESSAGE: &str = "{count}개의 누락된 데이터가 존재합니다!";
+
+// 누락되었던 항목이 처리되었을 때 보내는 메시지 ({count}는 처리된 개수)
+pub const RESOLVED_MESSAGE: &str = "누락되었던 항목 {count}개가 처리되었습니다.";
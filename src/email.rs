@@ -0,0 +1,430 @@
+// src/email.rs
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Duration,
+};
+
+use chrono::{DateTime, Duration as ChronoDuration, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use lettre::{
+    Message, SmtpTransport, Transport,
+    message::{Mailbox, MultiPart},
+    transport::smtp::authentication::Credentials,
+};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::{
+    consts::{DATE_FORMAT, DEFAULT_HTTP_TIMEOUT_SECS, DIGEST_MESSAGE, DIGEST_TITLE},
+    notification::MissingEntry,
+    notifier::{Alert, AlertEntry, Notifier, RetryPolicy, SendError, format_overdue},
+};
+
+/// SMTP 연결 보안 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    /// 연결 후 STARTTLS (기본 포트 587)
+    #[default]
+    StartTls,
+    /// 처음부터 TLS (기본 포트 465)
+    Tls,
+    /// 암호화하지 않음 (기본 포트 25). 사내 릴레이나 테스트용 서버에만 사용
+    None,
+}
+
+impl SmtpSecurity {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "starttls" => Ok(SmtpSecurity::StartTls),
+            "tls" | "ssl" => Ok(SmtpSecurity::Tls),
+            "none" => Ok(SmtpSecurity::None),
+            other => Err(format!(
+                "알 수 없는 보안 방식입니다: '{}' (starttls, tls, none)",
+                other
+            )),
+        }
+    }
+
+    fn default_port(self) -> u16 {
+        match self {
+            SmtpSecurity::StartTls => 587,
+            SmtpSecurity::Tls => 465,
+            SmtpSecurity::None => 25,
+        }
+    }
+}
+
+/// `type = email` 알림 방법 설정.
+///
+/// `host`, `port`, `security`, `username`, `password`, `from`, `to`(쉼표 구분, 여러 줄),
+/// `digest_at`(HH:MM, 지정하면 일일 요약 모드), `retries`, `backoff_ms`, `timeout_seconds`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailSettings {
+    pub host: String,
+    /// 생략하면 보안 방식의 기본 포트
+    pub port: Option<u16>,
+    pub security: SmtpSecurity,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from: String,
+    pub to: Vec<String>,
+    /// 일일 요약을 보내는 시각. 지정하면 알림마다 보내지 않고 모아서 하루 한 번 보냅니다.
+    pub digest_at: Option<NaiveTime>,
+    pub retry: RetryPolicy,
+    pub timeout: Duration,
+}
+
+impl Default for EmailSettings {
+    fn default() -> Self {
+        EmailSettings {
+            host: String::new(),
+            port: None,
+            security: SmtpSecurity::default(),
+            username: None,
+            password: None,
+            from: String::new(),
+            to: Vec::new(),
+            digest_at: None,
+            retry: RetryPolicy::default(),
+            timeout: Duration::from_secs(DEFAULT_HTTP_TIMEOUT_SECS),
+        }
+    }
+}
+
+impl EmailSettings {
+    /// 항목 적용. 알 수 없는 항목이면 Ok(false).
+    pub fn apply(&mut self, key: &str, value: &str) -> Result<bool, String> {
+        let value = value.trim();
+        match key {
+            "host" => self.host = value.to_string(),
+            "port" => {
+                self.port =
+                    Some(value.parse().ok().filter(|p| *p > 0).ok_or_else(|| {
+                        format!("1~65535 사이의 포트 번호여야 합니다: '{}'", value)
+                    })?)
+            }
+            "security" => self.security = SmtpSecurity::parse(value)?,
+            "username" => self.username = Some(value.to_string()),
+            "password" => self.password = Some(value.to_string()),
+            "from" => {
+                parse_mailbox(value)?;
+                self.from = value.to_string();
+            }
+            "to" => {
+                for address in value.split(',').map(str::trim).filter(|a| !a.is_empty()) {
+                    parse_mailbox(address)?;
+                    self.to.push(address.to_string());
+                }
+            }
+            "digest_at" => {
+                self.digest_at = Some(
+                    NaiveTime::parse_from_str(value, "%H:%M")
+                        .map_err(|_| format!("HH:MM 형식이어야 합니다: '{}'", value))?,
+                )
+            }
+            "timeout_seconds" => {
+                self.timeout = value
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .map(Duration::from_secs)
+                    .ok_or_else(|| format!("1 이상의 초(정수)여야 합니다: '{}'", value))?
+            }
+            _ => return self.retry.apply(key, value),
+        }
+        Ok(true)
+    }
+
+    /// 필수 항목 확인.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.host.is_empty() {
+            return Err("host 항목이 없습니다.".to_string());
+        }
+        if self.from.is_empty() {
+            return Err("from 항목이 없습니다.".to_string());
+        }
+        if self.to.is_empty() {
+            return Err("to 항목이 없습니다.".to_string());
+        }
+        if self.username.is_some() != self.password.is_some() {
+            return Err("username과 password는 함께 지정해야 합니다.".to_string());
+        }
+        Ok(())
+    }
+}
+
+fn parse_mailbox(address: &str) -> Result<Mailbox, String> {
+    address
+        .parse()
+        .map_err(|e| format!("메일 주소 형식 오류: '{}': {}", address, e))
+}
+
+// 일일 요약에 모아 둔 누락 항목
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DigestEntry {
+    workbook: String,
+    sheet: String,
+    row: usize,
+    scheduled: NaiveDateTime,
+    overdue_minutes: i64,
+    label: Option<String>,
+}
+
+// 일일 요약 상태. 재시작해도 모은 항목이 남도록 파일에 저장
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DigestState {
+    last_sent: Option<NaiveDate>,
+    entries: Vec<DigestEntry>,
+}
+
+impl DigestState {
+    fn load(path: &Path) -> Self {
+        let Ok(data) = fs::read_to_string(path) else {
+            return DigestState::default();
+        };
+        serde_json::from_str(&data).unwrap_or_else(|e| {
+            warn!(
+                "일일 요약 파일을 읽을 수 없어 새로 시작합니다: {}: {}",
+                path.display(),
+                e
+            );
+            DigestState::default()
+        })
+    }
+
+    fn save(&self, path: &Path) {
+        let temp_path = path.with_extension("json.tmp");
+        let result = serde_json::to_string_pretty(self)
+            .map_err(|e| e.to_string())
+            .and_then(|data| fs::write(&temp_path, data).map_err(|e| e.to_string()))
+            .and_then(|()| fs::rename(&temp_path, path).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            warn!("일일 요약 파일 저장 실패: {}: {}", path.display(), e);
+        }
+    }
+
+    // 같은 항목은 지연 시간만 갱신
+    fn add(&mut self, alert: &Alert) {
+        for item in &alert.entries {
+            let entry = &item.entry;
+            let overdue_minutes = entry.overdue.num_minutes();
+            match self.entries.iter_mut().find(|e| {
+                e.workbook == item.workbook
+                    && e.sheet == entry.sheet
+                    && e.row == entry.row
+                    && e.scheduled == entry.scheduled
+            }) {
+                Some(existing) => existing.overdue_minutes = overdue_minutes,
+                None => self.entries.push(DigestEntry {
+                    workbook: item.workbook.clone(),
+                    sheet: entry.sheet.clone(),
+                    row: entry.row,
+                    scheduled: entry.scheduled,
+                    overdue_minutes,
+                    label: entry.label.clone(),
+                }),
+            }
+        }
+    }
+
+    fn to_alert(&self, date: NaiveDate) -> Alert {
+        let mut entries: Vec<AlertEntry> = self
+            .entries
+            .iter()
+            .map(|e| AlertEntry {
+                workbook: e.workbook.clone(),
+                entry: MissingEntry {
+                    sheet: e.sheet.clone(),
+                    row: e.row,
+                    scheduled: e.scheduled,
+                    overdue: ChronoDuration::minutes(e.overdue_minutes),
+                    label: e.label.clone(),
+                },
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            (&a.workbook, &a.entry.sheet, a.entry.scheduled).cmp(&(
+                &b.workbook,
+                &b.entry.sheet,
+                b.entry.scheduled,
+            ))
+        });
+        let date = date.format(DATE_FORMAT).to_string();
+        Alert {
+            title: DIGEST_TITLE.replace("{date}", &date),
+            message: DIGEST_MESSAGE
+                .replace("{date}", &date)
+                .replace("{count}", &entries.len().to_string()),
+            entries,
+        }
+    }
+}
+
+// 일일 요약 설정과 상태
+struct Digest {
+    at: NaiveTime,
+    path: PathBuf,
+    state: Mutex<DigestState>,
+}
+
+/// SMTP로 누락 목록 메일(텍스트 + HTML)을 보내는 알림.
+///
+/// `digest_at`을 지정하면 누락 항목을 모아 두었다가 매일 그 시각에 한 번 요약 메일을 보냅니다.
+/// 요약 모드에서는 누락 항목이 없는 알림(처리됨, 데이터 지연 안내)은 메일로 보내지 않습니다.
+pub struct EmailNotifier {
+    transport: SmtpTransport,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+    retry: RetryPolicy,
+    digest: Option<Digest>,
+}
+
+impl EmailNotifier {
+    /// `digest_path`는 일일 요약 모드에서 모은 항목을 저장하는 파일입니다.
+    pub fn new(settings: &EmailSettings, digest_path: PathBuf) -> Result<Self, String> {
+        let builder = match settings.security {
+            SmtpSecurity::StartTls => SmtpTransport::starttls_relay(&settings.host),
+            SmtpSecurity::Tls => SmtpTransport::relay(&settings.host),
+            SmtpSecurity::None => Ok(SmtpTransport::builder_dangerous(&settings.host)),
+        }
+        .map_err(|e| format!("SMTP 서버 설정 오류: {}", e))?;
+        let mut builder = builder
+            .port(settings.port.unwrap_or(settings.security.default_port()))
+            .timeout(Some(settings.timeout));
+        if let (Some(username), Some(password)) = (&settings.username, &settings.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+        let digest = settings.digest_at.map(|at| Digest {
+            at,
+            state: Mutex::new(DigestState::load(&digest_path)),
+            path: digest_path,
+        });
+        Ok(EmailNotifier {
+            transport: builder.build(),
+            from: parse_mailbox(&settings.from)?,
+            to: settings
+                .to
+                .iter()
+                .map(|a| parse_mailbox(a))
+                .collect::<Result<_, _>>()?,
+            retry: settings.retry,
+            digest,
+        })
+    }
+
+    fn send(&self, alert: &Alert) -> Result<(), String> {
+        let mut builder = Message::builder()
+            .from(self.from.clone())
+            .subject(alert.title.clone());
+        for to in &self.to {
+            builder = builder.to(to.clone());
+        }
+        let email = builder
+            .multipart(MultiPart::alternative_plain_html(
+                plain_body(alert),
+                html_body(alert),
+            ))
+            .map_err(|e| format!("메일 작성 실패: {}", e))?;
+        self.retry.run("메일", || {
+            self.transport
+                .send(&email)
+                .map(|_| ())
+                .map_err(|e| SendError {
+                    message: format!("SMTP 오류: {}", e),
+                    retryable: !e.is_permanent(),
+                })
+        })
+    }
+}
+
+impl Notifier for EmailNotifier {
+    fn notify(&self, alert: &Alert) -> Result<(), String> {
+        let Some(digest) = &self.digest else {
+            return self.send(alert);
+        };
+        let mut state = digest.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.add(alert);
+        state.save(&digest.path);
+        info!(
+            "일일 요약에 추가: 누락 항목 {}개 ({} 발송 예정)",
+            state.entries.len(),
+            digest.at.format("%H:%M")
+        );
+        Ok(())
+    }
+
+    fn poll(&self, now: DateTime<Local>) -> Option<(String, Result<(), String>)> {
+        let digest = self.digest.as_ref()?;
+        let today = now.date_naive();
+        let mut state = digest.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.last_sent == Some(today) || now.time() < digest.at {
+            return None;
+        }
+        // 실패해도 오늘은 다시 보내지 않고, 모은 항목은 다음 요약에 포함
+        state.last_sent = Some(today);
+        if state.entries.is_empty() {
+            info!("일일 요약: 오늘 누락된 항목이 없어 메일을 보내지 않습니다.");
+            state.save(&digest.path);
+            return None;
+        }
+        let alert = state.to_alert(today);
+        let result = self.send(&alert);
+        if result.is_ok() {
+            state.entries.clear();
+        }
+        state.save(&digest.path);
+        Some((alert.title, result))
+    }
+}
+
+// 텍스트 본문. 시트별 목록은 missing.txt와 같은 형식
+fn plain_body(alert: &Alert) -> String {
+    let mut body = format!("{}\n", alert.message);
+    for ((workbook, sheet), entries) in alert.by_sheet() {
+        body.push_str(&format!("\n[{} / {}]\n", workbook, sheet));
+        for entry in entries {
+            body.push_str(&format!("{}\n", entry));
+        }
+    }
+    body
+}
+
+fn html_body(alert: &Alert) -> String {
+    let mut body = format!(
+        "<html><body>\n<h2>{}</h2>\n<p>{}</p>\n",
+        escape_html(&alert.title),
+        escape_html(&alert.message).replace('\n', "<br>")
+    );
+    for ((workbook, sheet), entries) in alert.by_sheet() {
+        body.push_str(&format!(
+            "<h3>{} / {} ({}건)</h3>\n",
+            escape_html(workbook),
+            escape_html(sheet),
+            entries.len()
+        ));
+        body.push_str(
+            "<table border=\"1\" cellpadding=\"4\" style=\"border-collapse:collapse\">\n\
+             <tr><th>예정 일시</th><th>행</th><th>지연</th><th>설명</th></tr>\n",
+        );
+        for entry in entries {
+            body.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                entry.scheduled.format("%Y-%m-%d %H:%M"),
+                entry.row,
+                format_overdue(entry.overdue),
+                escape_html(entry.label.as_deref().unwrap_or(""))
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+    body.push_str("</body></html>\n");
+    body
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
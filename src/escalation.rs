@@ -0,0 +1,102 @@
+// src/escalation.rs
+use chrono::Duration as ChronoDuration;
+
+use crate::template::Template;
+
+/// 쉼표로 구분한 알림 방법 이름 목록 파싱. 이름은 `[notifier:<이름>]` 또는 기본 제공 이름입니다.
+pub fn parse_channel_list(value: &str) -> Result<Vec<String>, String> {
+    channel_list(value.split(','))
+}
+
+/// 알림 방법 이름 목록. 빈 이름은 건너뛰고 중복은 한 번만 사용합니다.
+pub fn channel_list<'a>(names: impl IntoIterator<Item = &'a str>) -> Result<Vec<String>, String> {
+    let mut channels: Vec<String> = Vec::new();
+    for name in names.into_iter().map(str::trim).filter(|n| !n.is_empty()) {
+        if !channels.iter().any(|c| c == name) {
+            channels.push(name.to_string());
+        }
+    }
+    if channels.is_empty() {
+        return Err("알림 채널이 비어 있습니다.".to_string());
+    }
+    Ok(channels)
+}
+
+/// 지연 시간 기준 알림 단계. `[tier:<이름>]` 섹션에서 설정합니다.
+///
+/// 제목/메시지를 지정하지 않으면 `[title]`, `[message]` 설정을, 채널을 지정하지 않으면 `[notify]` 설정을 사용합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EscalationTier {
+    pub name: String,
+    /// 예정 시각으로부터 이만큼(분) 지난 항목부터 이 단계로 알림
+    pub after_minutes: i64,
+    pub title: Option<Template>,
+    pub message: Option<Template>,
+    /// 알림 방법 이름 목록
+    pub channels: Vec<String>,
+}
+
+impl EscalationTier {
+    pub fn new(name: String) -> Self {
+        EscalationTier {
+            name,
+            after_minutes: 0,
+            title: None,
+            message: None,
+            channels: Vec::new(),
+        }
+    }
+}
+
+/// 알림 단계 목록. 항목마다 지연 시간이 넘은 가장 높은 단계를 사용합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EscalationPolicy {
+    // after_minutes 오름차순
+    tiers: Vec<EscalationTier>,
+}
+
+impl EscalationPolicy {
+    /// 단계 목록으로 정책 생성. 비어 있으면 기본 단계 하나를 사용하고,
+    /// 채널을 지정하지 않은 단계는 `default_channels`로 보냅니다.
+    pub fn new(
+        mut tiers: Vec<EscalationTier>,
+        default_channels: &[String],
+    ) -> Result<Self, String> {
+        if tiers.is_empty() {
+            tiers.push(EscalationTier::new("기본".to_string()));
+        }
+        for tier in &mut tiers {
+            if tier.channels.is_empty() {
+                tier.channels = default_channels.to_vec();
+            }
+        }
+        tiers.sort_by_key(|t| t.after_minutes);
+        if let Some(pair) = tiers
+            .windows(2)
+            .find(|pair| pair[0].after_minutes == pair[1].after_minutes)
+        {
+            return Err(format!(
+                "[tier:{}]와 [tier:{}]의 after_minutes({})가 같습니다.",
+                pair[0].name, pair[1].name, pair[0].after_minutes
+            ));
+        }
+        Ok(EscalationPolicy { tiers })
+    }
+
+    pub fn tiers(&self) -> &[EscalationTier] {
+        &self.tiers
+    }
+
+    pub fn tier(&self, index: usize) -> &EscalationTier {
+        &self.tiers[index.min(self.tiers.len() - 1)]
+    }
+
+    /// 지연 시간에 해당하는 단계 번호. 가장 낮은 단계보다 덜 지났으면 0.
+    pub fn tier_index(&self, overdue: ChronoDuration) -> usize {
+        let minutes = overdue.num_minutes();
+        self.tiers
+            .iter()
+            .rposition(|t| t.after_minutes <= minutes)
+            .unwrap_or(0)
+    }
+}
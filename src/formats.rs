@@ -0,0 +1,208 @@
+// src/formats.rs
+use calamine::DataType;
+use chrono::{
+    Datelike, Duration as ChronoDuration, NaiveDate, NaiveDateTime, NaiveTime,
+    format::{Item, StrftimeItems},
+};
+
+use crate::{
+    consts::{DEFAULT_DATE_FORMATS, DEFAULT_DATETIME_FORMATS, DEFAULT_TIME_FORMATS},
+    utils::{excel_serial_to_date, excel_serial_to_datetime, excel_serial_to_time},
+};
+
+// 연도 정보가 들어 있는 형식 지정자
+const YEAR_SPECIFIERS: &[&str] = &["%Y", "%y", "%G", "%g", "%F", "%D", "%c", "%x", "%+", "%s"];
+// 연도 없는 날짜가 이만큼 이상 미래면 작년 날짜로 간주 (연말연초 대비)
+const YEARLESS_FUTURE_LIMIT_DAYS: i64 = 180;
+
+/// 문자열 셀의 날짜/시간을 해석할 chrono 형식 목록.
+///
+/// 기본 형식 뒤에 `[formats]` 섹션의 `date`, `time`, `datetime` 항목이 추가됩니다.
+/// "오전"/"오후"는 AM/PM으로 바꾼 뒤 해석하고, 연도가 없는 날짜("4월 19일")는 올해로 봅니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateTimeFormats {
+    pub date: Vec<String>,
+    pub time: Vec<String>,
+    pub datetime: Vec<String>,
+}
+
+impl Default for DateTimeFormats {
+    fn default() -> Self {
+        let to_vec = |formats: &[&str]| formats.iter().map(|f| f.to_string()).collect();
+        DateTimeFormats {
+            date: to_vec(DEFAULT_DATE_FORMATS),
+            time: to_vec(DEFAULT_TIME_FORMATS),
+            datetime: to_vec(DEFAULT_DATETIME_FORMATS),
+        }
+    }
+}
+
+impl DateTimeFormats {
+    pub fn add_date(&mut self, format: &str) -> Result<(), String> {
+        add_format(&mut self.date, format)
+    }
+
+    pub fn add_time(&mut self, format: &str) -> Result<(), String> {
+        add_format(&mut self.time, format)
+    }
+
+    pub fn add_datetime(&mut self, format: &str) -> Result<(), String> {
+        add_format(&mut self.datetime, format)
+    }
+
+    /// 날짜 문자열 해석. `today`는 연도 없는 형식의 연도를 정할 때 사용합니다.
+    pub fn parse_date(&self, value: &str, today: NaiveDate) -> Option<NaiveDate> {
+        let value = normalize(value);
+        self.date.iter().find_map(|format| {
+            parse_with_year(&value, format, today, |s, f| {
+                NaiveDate::parse_from_str(s, f).ok()
+            })
+        })
+    }
+
+    pub fn parse_time(&self, value: &str) -> Option<NaiveTime> {
+        let value = normalize(value);
+        self.time
+            .iter()
+            .find_map(|format| NaiveTime::parse_from_str(&value, format).ok())
+    }
+
+    /// 일시 문자열 해석. `datetime` 형식을 먼저 시도하고, 없으면 "날짜 시간" 조합을 시도합니다.
+    pub fn parse_datetime(&self, value: &str, today: NaiveDate) -> Option<NaiveDateTime> {
+        let value = normalize(value);
+        let parse = |format: &str| {
+            parse_with_year(&value, format, today, |s, f| {
+                NaiveDateTime::parse_from_str(s, f).ok()
+            })
+        };
+
+        self.datetime
+            .iter()
+            .find_map(|format| parse(format))
+            .or_else(|| {
+                self.date.iter().find_map(|date_format| {
+                    self.time
+                        .iter()
+                        .find_map(|time_format| parse(&format!("{} {}", date_format, time_format)))
+                })
+            })
+    }
+
+    /// 셀 값을 날짜로 변환. 빈 셀은 Ok(None), 날짜로 볼 수 없는 값은 Err.
+    pub fn cell_to_date(
+        &self,
+        cell: Option<&DataType>,
+        today: NaiveDate,
+    ) -> Result<Option<NaiveDate>, String> {
+        match cell {
+            None | Some(DataType::Empty) => Ok(None),
+            Some(DataType::String(s)) if s.trim().is_empty() => Ok(None),
+            Some(DataType::String(s)) | Some(DataType::DateTimeIso(s)) => self
+                .parse_date(s, today)
+                .or_else(|| self.parse_datetime(s, today).map(|dt| dt.date()))
+                .map(Some)
+                .ok_or_else(|| format!("'{}'은(는) 설정된 날짜 형식과 맞지 않음", s.trim())),
+            Some(other) => excel_serial(other)
+                .and_then(excel_serial_to_date)
+                .map(Some)
+                .ok_or_else(|| format!("{:?} 타입은 날짜로 처리 불가", other)),
+        }
+    }
+
+    /// 셀 값을 시각으로 변환. 일련번호는 소수부만 사용합니다.
+    pub fn cell_to_time(&self, cell: Option<&DataType>) -> Result<Option<NaiveTime>, String> {
+        match cell {
+            None | Some(DataType::Empty) => Ok(None),
+            Some(DataType::String(s)) if s.trim().is_empty() => Ok(None),
+            Some(DataType::String(s)) | Some(DataType::DateTimeIso(s)) => self
+                .parse_time(s)
+                .or_else(|| self.parse_datetime(s, NaiveDate::MIN).map(|dt| dt.time()))
+                .map(Some)
+                .ok_or_else(|| format!("'{}'은(는) 설정된 시간 형식과 맞지 않음", s.trim())),
+            Some(other) => excel_serial(other)
+                .and_then(excel_serial_to_time)
+                .map(Some)
+                .ok_or_else(|| format!("{:?} 타입은 시간으로 처리 불가", other)),
+        }
+    }
+
+    /// 한 셀에 날짜와 시각이 함께 있는 값을 일시로 변환.
+    pub fn cell_to_datetime(
+        &self,
+        cell: Option<&DataType>,
+        today: NaiveDate,
+    ) -> Result<Option<NaiveDateTime>, String> {
+        match cell {
+            None | Some(DataType::Empty) => Ok(None),
+            Some(DataType::String(s)) if s.trim().is_empty() => Ok(None),
+            Some(DataType::String(s)) | Some(DataType::DateTimeIso(s)) => self
+                .parse_datetime(s, today)
+                .map(Some)
+                .ok_or_else(|| format!("'{}'은(는) 설정된 일시 형식과 맞지 않음", s.trim())),
+            Some(other) => excel_serial(other)
+                .and_then(excel_serial_to_datetime)
+                .map(Some)
+                .ok_or_else(|| format!("{:?} 타입은 일시로 처리 불가", other)),
+        }
+    }
+}
+
+/// chrono 형식 문자열 검증. 잘못된 지정자가 있으면 Err.
+pub fn validate_format(format: &str) -> Result<(), String> {
+    if format.trim().is_empty() {
+        return Err("형식이 비어 있습니다.".to_string());
+    }
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(format!("잘못된 날짜/시간 형식: '{}'", format));
+    }
+    Ok(())
+}
+
+fn add_format(formats: &mut Vec<String>, format: &str) -> Result<(), String> {
+    let format = format.trim();
+    validate_format(format)?;
+    if !formats.iter().any(|f| f == format) {
+        formats.push(format.to_string());
+    }
+    Ok(())
+}
+
+// 앞뒤 공백 제거, 연속 공백 정리, 한국어 오전/오후 표기를 AM/PM으로 변환
+fn normalize(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("오전", "AM")
+        .replace("오후", "PM")
+}
+
+// 연도 없는 형식은 올해(또는 너무 먼 미래면 작년) 연도를 붙여서 해석
+fn parse_with_year<T: Datelike>(
+    value: &str,
+    format: &str,
+    today: NaiveDate,
+    parse: impl Fn(&str, &str) -> Option<T>,
+) -> Option<T> {
+    if YEAR_SPECIFIERS.iter().any(|spec| format.contains(spec)) {
+        return parse(value, format);
+    }
+
+    let with_year = |year: i32| parse(&format!("{} {}", value, year), &format!("{} %Y", format));
+    let parsed = with_year(today.year())?;
+    let date = NaiveDate::from_ymd_opt(parsed.year(), parsed.month(), parsed.day())?;
+    if date - today > ChronoDuration::days(YEARLESS_FUTURE_LIMIT_DAYS) {
+        with_year(today.year() - 1)
+    } else {
+        Some(parsed)
+    }
+}
+
+// 숫자/날짜 셀의 엑셀 일련번호 값
+fn excel_serial(cell: &DataType) -> Option<f64> {
+    match cell {
+        DataType::Float(f) | DataType::DateTime(f) | DataType::Duration(f) => Some(*f),
+        DataType::Int(i) => Some(*i as f64),
+        _ => None,
+    }
+}
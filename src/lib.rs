@@ -1,3 +1,19 @@
+pub mod alert_state;
+pub mod clock;
+pub mod completion;
+pub mod config_file;
+pub mod console;
 pub mod consts;
+pub mod email;
+pub mod escalation;
+pub mod formats;
 pub mod notification;
+pub mod notifier;
+pub mod schedule;
+pub mod snapshot;
+pub mod source;
+pub mod teams;
+pub mod template;
 pub mod utils;
+pub mod watcher;
+pub mod webhook;
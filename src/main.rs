@@ -1,51 +1,207 @@
 // src/main.rs
-use log::{error, info}; // 또는 tracing 사용
-use std::error::Error;
+use log::{error, info, warn}; // 또는 tracing 사용
+use std::{error::Error, io, path::PathBuf, process::ExitCode, sync::Arc};
+
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use clap::{Parser, Subcommand};
 
 use checker::{
-    consts::CONFIG_FILE_NAME,
-    notification::start_notification_service,
-    utils::{get_executable_dir, read_config, setup_logger},
+    clock::{Clock, FixedClock, SystemClock},
+    config_file::{ConfigSource, find_config_file},
+    consts::{EXIT_ERROR, EXIT_MISSING},
+    notification::{check_for_missed_notifications, start_notification_service},
+    snapshot::SnapshotMode,
+    utils::{
+        Config, describe_config, get_executable_dir, set_log_level, setup_logger, warning_count,
+        write_report,
+    },
     // validation 모듈 임포트는 이제 notification 모듈에서 사용
 };
 
+/// 엑셀 알림 처리 누락 검사 도구.
+#[derive(Parser)]
+#[command(name = "checker", version)]
+struct Cli {
+    /// 설정 파일(.toml 또는 .cfg). 생략하면 실행 파일 폴더의 config.toml, config.cfg 순으로 찾습니다.
+    #[arg(long, global = true, value_name = "파일")]
+    config: Option<PathBuf>,
+    /// 검사할 엑셀 파일. 설정의 워크북 경로 대신 사용합니다 (워크북이 하나일 때만).
+    #[arg(long, global = true, value_name = "파일")]
+    excel: Option<PathBuf>,
+    /// validate-config와 같음 (이전 버전 호환)
+    #[arg(long, hide = true)]
+    check_config: bool,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 주기적 검사 서비스 실행 (명령을 생략하면 이 명령)
+    Run,
+    /// 한 번 검사하고 결과를 출력. 누락 항목이 있으면 종료 코드 1, 검사 오류는 2
+    CheckOnce,
+    /// 설정 파일을 확인하고 적용될 설정을 출력. 워크북 파일이 없으면 종료 코드 2
+    ValidateConfig,
+    /// 지정한 과거 시각 기준으로 검사한 결과를 출력. 종료 코드는 check-once와 같음
+    Report {
+        /// 기준 시각 (예: "2025-04-19 21:26")
+        #[arg(long, value_name = "일시", value_parser = parse_at)]
+        at: DateTime<Local>,
+    },
+}
+
+// "YYYY-MM-DD HH:MM[:SS]"를 로컬 시각으로
+fn parse_at(value: &str) -> Result<DateTime<Local>, String> {
+    let naive = [
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(value.trim(), format).ok())
+    .ok_or_else(|| format!("'YYYY-MM-DD HH:MM' 형식이어야 합니다: '{}'", value))?;
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .ok_or_else(|| format!("로컬 시간대에 없는 시각입니다: '{}'", value))
+}
+
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
     setup_logger();
 
-    info!("팀즈 알림 누락 주기적 검사 도구를 시작합니다...");
+    let command = match cli.command {
+        Some(command) => command,
+        None if cli.check_config => Command::ValidateConfig,
+        None => Command::Run,
+    };
+    // 한 번 실행하는 명령은 결과만 보이도록 경고 이상만 출력 (RUST_LOG로 바꿀 수 있음)
+    if !matches!(command, Command::Run)
+        && std::env::var_os("RUST_LOG").is_none()
+        && let Err(e) = set_log_level("warn")
+    {
+        warn!("로그 수준 변경 실패: {}", e);
+    }
 
-    let exe_dir = get_executable_dir()?; // exe_dir 얻기
-    info!("실행 파일 디렉토리: {:?}", exe_dir);
-    let config_path = exe_dir.join(CONFIG_FILE_NAME);
+    let result = match get_executable_dir() {
+        Ok(exe_dir) => {
+            info!("실행 파일 디렉토리: {:?}", exe_dir);
+            let source = ConfigSource {
+                path: cli.config.unwrap_or_else(|| find_config_file(&exe_dir)),
+                excel: cli.excel,
+            };
+            run_command(command, &source, exe_dir).await
+        }
+        Err(e) => Err(e),
+    };
+    // 오류는 발생한 곳에서 로그에 남기므로 여기서는 표준 오류로만 알림
+    result.unwrap_or_else(|e| {
+        eprintln!("오류: {}", e);
+        ExitCode::from(EXIT_ERROR)
+    })
+}
 
-    info!("설정 파일 읽는 중: {:?}", config_path);
-    let config = read_config(&config_path).map_err(|e| {
+async fn run_command(
+    command: Command,
+    source: &ConfigSource,
+    exe_dir: PathBuf,
+) -> Result<ExitCode, Box<dyn Error>> {
+    info!("설정 파일 읽는 중: {:?}", source.path);
+    let config = source.load().map_err(|e| {
         error!("설정 파일 처리 중 오류 발생: {}", e);
         e
     })?;
-    info!(" - Excel 경로: {}", config.excel_path.display());
-    info!(" - 관리 대상 시트: {:?}", config.manage_games);
-    info!(" - 알림 제목 (설정됨): {:?}", config.notification_title);
-    info!(
-        " - 알림 메시지 템플릿 (설정됨): {:?}",
-        config.notification_message_template
-    );
-
-    if !config.excel_path.exists() {
-        error!(
-            "설정된 Excel 파일을 찾을 수 없습니다: {}",
-            config.excel_path.display()
-        );
-        return Err(format!("Excel file not found: {}", config.excel_path.display()).into());
+
+    match command {
+        Command::Run => run_service(config, source, exe_dir).await,
+        Command::CheckOnce => print_check(&config, &SystemClock),
+        Command::ValidateConfig => {
+            println!("설정 파일: {}", source.path.display());
+            for line in describe_config(&config) {
+                println!(" - {}", line);
+            }
+            let missing_paths: Vec<_> = config
+                .workbooks
+                .iter()
+                .filter(|w| !w.path.exists())
+                .map(|w| w.path.display())
+                .collect();
+            for path in &missing_paths {
+                println!(" ! 파일을 찾을 수 없습니다: {}", path);
+            }
+            // 설정을 읽으며 남긴 경고 (알 수 없는 항목, 쓰이지 않는 시트 설정 등)
+            let warnings = warning_count();
+            if warnings > 0 {
+                println!("설정 경고 {}건이 있습니다. 위 경고를 확인하세요.", warnings);
+            }
+            if !missing_paths.is_empty() {
+                println!("워크북 파일 {}개를 찾을 수 없습니다.", missing_paths.len());
+                return Ok(ExitCode::from(EXIT_ERROR));
+            }
+            if warnings == 0 {
+                println!("설정에 오류가 없습니다.");
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Report { at } => {
+            println!("기준 시각: {}", at.format("%Y-%m-%d %H:%M:%S"));
+            print_check(&config, &FixedClock::new(at))
+        }
+    }
+}
+
+// 한 번 검사해서 missing.txt 형식으로 출력. 알림은 보내지 않고 알림 상태와 스냅샷도 바꾸지 않음
+fn print_check(config: &Config, clock: &dyn Clock) -> Result<ExitCode, Box<dyn Error>> {
+    let result = check_for_missed_notifications(config, clock, SnapshotMode::ReadOnly)?;
+    write_report(&mut io::stdout().lock(), &result)?;
+    let has_errors = result
+        .workbooks
+        .iter()
+        .any(|w| w.error.is_some() || !w.sheet_errors.is_empty());
+    Ok(if result.missing_count() > 0 {
+        ExitCode::from(EXIT_MISSING)
+    } else if has_errors {
+        ExitCode::from(EXIT_ERROR)
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+async fn run_service(
+    config: Config,
+    source: &ConfigSource,
+    exe_dir: PathBuf,
+) -> Result<ExitCode, Box<dyn Error>> {
+    info!("팀즈 알림 누락 주기적 검사 도구를 시작합니다...");
+    for line in describe_config(&config) {
+        info!(" - {}", line);
+    }
+
+    let missing_paths: Vec<String> = config
+        .workbooks
+        .iter()
+        .filter(|w| !w.path.exists())
+        .map(|w| w.path.display().to_string())
+        .collect();
+    for path in &missing_paths {
+        warn!("설정된 Excel 파일을 찾을 수 없습니다: {}", path);
+    }
+    if missing_paths.len() == config.workbooks.len() {
+        error!("설정된 Excel 파일을 하나도 찾을 수 없습니다.");
+        return Err(format!("Excel file not found: {}", missing_paths.join(", ")).into());
     }
 
     info!("주기적 알림 확인 서비스 시작...");
-    if let Err(e) = start_notification_service(&config, &exe_dir).await {
+    if let Err(e) =
+        start_notification_service(config, source, &exe_dir, Arc::new(SystemClock)).await
+    {
         error!("알림 서비스 실행 중 심각한 오류 발생: {}", e);
         return Err(e);
     }
 
-    info!("알림 확인 서비스가 정상적으로 종료되었습니다 (예상치 못한 상황).");
-    Ok(())
+    info!("알림 확인 서비스가 정상적으로 종료되었습니다.");
+    Ok(ExitCode::SUCCESS)
 }
@@ -1,155 +1,513 @@
 // src/notification.rs
-use std::{collections::HashMap, error::Error, path::PathBuf, process::Command};
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fmt,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+};
 
-use calamine::{DataType, Reader, Xlsx, open_workbook};
-// --- chrono::NaiveTime 추가 ---
-use chrono::{Local, NaiveTime, Timelike};
+use calamine::{DataType, Range};
+use chrono::{DateTime, Local, NaiveDateTime};
 // --- Duration도 chrono에서 직접 사용 ---
 use chrono::Duration as ChronoDuration;
-use tokio::time::{Duration, sleep};
+use tokio::{
+    sync::mpsc::Receiver,
+    task::{self, JoinError, JoinHandle},
+    time::{Duration, Instant, sleep_until},
+};
 use tracing::{debug, error, info, warn};
 
 use crate::{
-    consts::{DATE_FORMAT, OUTPUT_FILE_NAME},
-    utils::{Config, excel_date_to_string, excel_time_to_string, write_missing_report},
+    alert_state::{AlertRecord, AlertState, AlertUpdate},
+    clock::Clock,
+    completion::RowStatus,
+    config_file::ConfigSource,
+    console::{ConsoleCommand, spawn_console},
+    consts::{
+        ALERT_STATE_FILE_NAME, DATE_HEADERS, DATETIME_HEADERS, DESCRIPTION_HEADERS, DONE_HEADERS,
+        OUTPUT_FILE_NAME, RESOLVED_MESSAGE, TIME_HEADERS,
+    },
+    notifier::{Alert, AlertEntry, Delivery, NotifierRegistry},
+    schedule::Scheduler,
+    snapshot::{Fallback, SnapshotMode, open_with_snapshot},
+    template::TemplateContext,
+    utils::{
+        ColumnRef, Config, SheetConfig, WorkbookConfig, column_letter, describe_config,
+        write_missing_report,
+    },
+    watcher::{WatchSettings, spawn_watcher},
 };
 
-pub type NotificationList = HashMap<String, Vec<String>>;
+/// 누락으로 판단된 행 하나.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingEntry {
+    pub sheet: String,
+    /// 시트 기준 행 번호 (1부터 시작)
+    pub row: usize,
+    /// 알림 예정 일시
+    pub scheduled: NaiveDateTime,
+    /// 검사 시각 기준 예정 일시로부터 지난 시간
+    pub overdue: ChronoDuration,
+    /// 설명 열 값
+    pub label: Option<String>,
+}
+
+impl fmt::Display for MissingEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}행, {}분 지연)",
+            self.scheduled.format("%Y-%m-%d %H:%M:%S"),
+            self.row,
+            self.overdue.num_minutes()
+        )?;
+        if let Some(label) = &self.label {
+            write!(f, " {}", label)?;
+        }
+        Ok(())
+    }
+}
+
+/// 시트 이름별 누락 항목 목록.
+pub type NotificationList = HashMap<String, Vec<MissingEntry>>;
+
+/// 워크북 하나의 검사 결과. 시트 단위 오류(시트 없음, 헤더 누락 등)는 검사를 중단하지 않고 따로 모읍니다.
+#[derive(Debug, Default)]
+pub struct WorkbookResult {
+    pub name: String,
+    pub path: PathBuf,
+    /// 설정된 시트 이름. 검사하지 못한 시트도 포함합니다.
+    pub sheets: Vec<String>,
+    pub missing: NotificationList,
+    pub sheet_errors: HashMap<String, String>,
+    /// 워크북을 열지 못한 경우의 오류
+    pub error: Option<String>,
+    /// 원본을 읽지 못해 대신 사용한 이전 스냅샷
+    pub fallback: Option<Fallback>,
+    /// 사용한 스냅샷이 `[snapshot] stale_minutes`보다 오래됨
+    pub stale: bool,
+}
+
+/// 누락 검사 1회의 결과. 설정된 워크북 순서대로 담깁니다.
+#[derive(Debug, Default)]
+pub struct CheckResult {
+    pub workbooks: Vec<WorkbookResult>,
+    /// 검사 기간의 시작 시각
+    pub window_start: NaiveDateTime,
+}
+
+impl CheckResult {
+    /// 전체 워크북의 누락 항목 개수.
+    pub fn missing_count(&self) -> usize {
+        self.workbooks
+            .iter()
+            .flat_map(|w| w.missing.values())
+            .map(|entries| entries.len())
+            .sum()
+    }
+
+    /// 오래된 스냅샷으로 검사한 워크북.
+    pub fn stale_workbooks(&self) -> impl Iterator<Item = &WorkbookResult> {
+        self.workbooks.iter().filter(|w| w.stale)
+    }
+}
+
+// 예정 일시를 읽을 열. 날짜/시간 열이 나뉘어 있거나, 한 열에 일시가 함께 들어 있음
+#[derive(Debug, Clone, Copy)]
+enum ScheduleColumns {
+    Split { date: usize, time: usize },
+    Combined(usize),
+}
+
+// 시트 열 매핑을 실제 열 번호(0부터 시작, 시트 기준)로 변환한 결과
+struct ResolvedColumns {
+    schedule: ScheduleColumns,
+    done: usize,
+    description: Option<usize>,
+    // 헤더 행의 range 내 위치. 이 행까지는 데이터로 보지 않음
+    header_row: Option<usize>,
+}
+
+// 셀 텍스트가 헤더 이름과 일치하는지 (앞뒤 공백, 영문 대소문자 무시)
+fn header_matches(cell: &DataType, name: &str) -> bool {
+    cell.to_string().trim().eq_ignore_ascii_case(name.trim())
+}
+
+// 시트 기준 열 번호로 행의 셀 조회
+fn cell_at(row: &[DataType], start_col: usize, column: usize) -> Option<&DataType> {
+    column.checked_sub(start_col).and_then(|i| row.get(i))
+}
+
+// 헤더 행을 찾고, 열 매핑을 시트 기준 열 번호로 변환
+fn resolve_columns(
+    sheet: &SheetConfig,
+    range: &Range<DataType>,
+) -> Result<ResolvedColumns, String> {
+    let (start_row, start_col) = range.start().unwrap_or((0, 0));
+    let mapping = &sheet.columns;
+    let combined = mapping.datetime.is_some();
+    let mut fields: Vec<(&str, &Option<ColumnRef>, &[&str], usize)> = if combined {
+        vec![("일시", &mapping.datetime, DATETIME_HEADERS, 1)]
+    } else {
+        vec![
+            ("날짜", &mapping.date, DATE_HEADERS, 1),
+            ("시간", &mapping.time, TIME_HEADERS, 2),
+        ]
+    };
+    fields.push(("완료", &mapping.done, DONE_HEADERS, 3));
+
+    let header_row = match sheet.header_row {
+        Some(row_number) => {
+            let index = (row_number - 1)
+                .checked_sub(start_row as usize)
+                .filter(|i| *i < range.height())
+                .ok_or_else(|| {
+                    format!("설정된 헤더 행 {}이 시트 범위를 벗어났습니다.", row_number)
+                })?;
+            Some(index)
+        }
+        None => {
+            // 첫 행에 알려진 헤더 이름이 하나라도 있으면 헤더 행으로 간주
+            let configured_headers = [&mapping.date, &mapping.time, &mapping.datetime]
+                .into_iter()
+                .chain([&mapping.done, &mapping.description])
+                .filter_map(|c| match c {
+                    Some(ColumnRef::Header(name)) => Some(name.as_str()),
+                    _ => None,
+                });
+            let known_headers: Vec<&str> = fields
+                .iter()
+                .flat_map(|(_, _, defaults, _)| defaults.iter().copied())
+                .chain(DESCRIPTION_HEADERS.iter().copied())
+                .chain(configured_headers)
+                .collect();
+            range
+                .rows()
+                .next()
+                .filter(|row| {
+                    row.iter()
+                        .any(|cell| known_headers.iter().any(|h| header_matches(cell, h)))
+                })
+                .map(|_| 0)
+        }
+    };
+    let header_cells = header_row.and_then(|i| range.rows().nth(i));
+
+    let find_header = |names: &[&str]| -> Option<usize> {
+        header_cells.and_then(|row| {
+            row.iter()
+                .position(|cell| names.iter().any(|name| header_matches(cell, name)))
+                .map(|i| i + start_col as usize)
+        })
+    };
+
+    let mut errors = Vec::new();
+    let mut resolved = vec![0usize; fields.len()];
+    for (slot, (label, column, defaults, default_index)) in resolved.iter_mut().zip(fields) {
+        let found = match column {
+            Some(ColumnRef::Index(index)) => Some(*index),
+            Some(ColumnRef::Header(name)) => find_header(&[name.as_str()]),
+            // 헤더 행이 있으면 기본 헤더 이름으로 찾고, 없으면 기본 열 위치(B/C/D)를 사용
+            None if header_cells.is_some() => find_header(defaults),
+            None => Some(default_index),
+        };
+        match found {
+            Some(index) => *slot = index,
+            None => {
+                let expected = match column {
+                    Some(ColumnRef::Header(name)) => format!("'{}'", name),
+                    _ => defaults
+                        .iter()
+                        .map(|h| format!("'{}'", h))
+                        .collect::<Vec<_>>()
+                        .join("/"),
+                };
+                errors.push(format!("{} 열({})", label, expected));
+            }
+        }
+    }
+
+    let description = match &mapping.description {
+        Some(ColumnRef::Index(index)) => Some(*index),
+        Some(ColumnRef::Header(name)) => match find_header(&[name.as_str()]) {
+            Some(index) => Some(index),
+            None => {
+                errors.push(format!("설명 열('{}')", name));
+                None
+            }
+        },
+        None => find_header(DESCRIPTION_HEADERS),
+    };
 
-fn check_for_missed_notifications(config: &Config) -> Result<NotificationList, Box<dyn Error>> {
-    info!("누락 알림 확인 시작 (오늘 날짜 & 과거 시간 & 9분 경과 미완료 항목 확인)");
-    let mut excel: Xlsx<_> = open_workbook(&config.excel_path).map_err(|e| {
-        error!("엑셀 파일 열기 실패: {}", e);
-        e
-    })?;
+    if !errors.is_empty() {
+        return Err(match header_row {
+            Some(i) => format!(
+                "헤더 행({}행)에서 다음 열을 찾을 수 없습니다: {}",
+                start_row as usize + i + 1,
+                errors.join(", ")
+            ),
+            None => format!(
+                "헤더 행을 찾을 수 없어 다음 열을 확인할 수 없습니다: {}",
+                errors.join(", ")
+            ),
+        });
+    }
 
-    let now = Local::now();
-    let today_str = now.format(DATE_FORMAT).to_string();
-    let current_naive_time = now.time();
+    let schedule = if combined {
+        ScheduleColumns::Combined(resolved[0])
+    } else {
+        ScheduleColumns::Split {
+            date: resolved[0],
+            time: resolved[1],
+        }
+    };
+
+    Ok(ResolvedColumns {
+        schedule,
+        done: resolved[resolved.len() - 1],
+        description,
+        header_row,
+    })
+}
+
+/// 설정된 시트에서 `clock` 기준 누락 항목을 찾습니다. `snapshot_mode`는 원본을 읽은 뒤 스냅샷을 저장할지 정합니다.
+pub fn check_for_missed_notifications(
+    config: &Config,
+    clock: &dyn Clock,
+    snapshot_mode: SnapshotMode,
+) -> Result<CheckResult, Box<dyn Error>> {
+    info!(
+        "누락 알림 확인 시작 (검사 기간 {} & 과거 시간 & {}분 경과 미완료 항목 확인)",
+        config.lookback, config.grace_minutes
+    );
+    let local_now = clock.now();
+    let now = local_now.naive_local();
+    let window_start = config.lookback.window_start(now);
 
     info!(
-        "오늘 날짜: {}, 현재 시각: {}",
-        today_str,
-        current_naive_time.format("%H:%M:%S")
+        "현재 시각: {}, 검사 시작 시각: {}",
+        now.format("%Y-%m-%d %H:%M:%S"),
+        window_start.format("%Y-%m-%d %H:%M:%S")
+    );
+
+    let workbooks: Vec<WorkbookResult> = config
+        .workbooks
+        .iter()
+        .map(|workbook| check_workbook(config, workbook, snapshot_mode, local_now, window_start))
+        .collect();
+
+    // 모든 워크북을 열지 못했으면 검사 자체가 실패한 것으로 처리
+    if workbooks.iter().all(|w| w.error.is_some()) {
+        let errors: Vec<&str> = workbooks
+            .iter()
+            .filter_map(|w| w.error.as_deref())
+            .collect();
+        return Err(errors.join(", ").into());
+    }
+
+    Ok(CheckResult {
+        workbooks,
+        window_start,
+    })
+}
+
+// 워크북 하나 검사. 파일을 열지 못하면 `error`에 기록
+fn check_workbook(
+    config: &Config,
+    workbook: &WorkbookConfig,
+    snapshot_mode: SnapshotMode,
+    local_now: DateTime<Local>,
+    window_start: NaiveDateTime,
+) -> WorkbookResult {
+    let now = local_now.naive_local();
+    let mut result = WorkbookResult {
+        name: workbook.name.clone(),
+        path: workbook.path.clone(),
+        sheets: workbook.sheets.iter().map(|s| s.name.clone()).collect(),
+        ..Default::default()
+    };
+
+    debug!(
+        "워크북 '{}' 확인 중: {}",
+        workbook.name,
+        workbook.path.display()
     );
+    let mut source = match open_with_snapshot(workbook, &config.snapshot, snapshot_mode, local_now)
+    {
+        Ok((source, fallback)) => {
+            if let Some(fallback) = &fallback {
+                result.stale =
+                    fallback.age >= ChronoDuration::minutes(config.snapshot.stale_minutes);
+                let message = format!(
+                    "워크북 '{}' 원본을 읽지 못해 {} 스냅샷({}분 전)으로 검사합니다: {}",
+                    workbook.name,
+                    fallback.taken_at.format("%Y-%m-%d %H:%M:%S"),
+                    fallback.age.num_minutes(),
+                    fallback.error
+                );
+                if result.stale {
+                    error!("{}", message);
+                } else {
+                    warn!("{}", message);
+                }
+            }
+            result.fallback = fallback;
+            source
+        }
+        Err(e) => {
+            error!("워크북 '{}' 파일 열기 실패: {}", workbook.name, e);
+            result.error = Some(format!("파일 열기 실패: {}", e));
+            return result;
+        }
+    };
 
     let mut missing_notifications: NotificationList = HashMap::new();
-    let grace_period = ChronoDuration::minutes(9);
+    let mut sheet_errors = HashMap::new();
 
-    for sheet_name in &config.manage_games {
-        debug!(" - 시트 '{}' 확인 중...", sheet_name);
-        match excel.worksheet_range(sheet_name) {
+    for sheet in &workbook.sheets {
+        let sheet_name = &sheet.name;
+        let grace_minutes = sheet.grace_minutes.unwrap_or(config.grace_minutes);
+        let grace_period = ChronoDuration::minutes(grace_minutes);
+        debug!(
+            " - 시트 '{}' 확인 중 (유예 {}분)...",
+            sheet_name, grace_minutes
+        );
+        match source.sheet_range(sheet_name) {
             Ok(range) => {
+                let columns = match resolve_columns(sheet, &range) {
+                    Ok(columns) => columns,
+                    Err(e) => {
+                        error!("시트 '{}' 열 설정 오류: {}", sheet_name, e);
+                        sheet_errors.insert(sheet_name.clone(), e);
+                        continue;
+                    }
+                };
+                // range는 사용된 첫 셀부터 시작하므로 시트 기준 행/열 번호로 보정
+                let (start_row, start_col) = range.start().unwrap_or((0, 0));
+                let start_col = start_col as usize;
+                let data_start = columns.header_row.map_or(0, |i| i + 1);
+
                 let mut current_sheet_missing = Vec::new();
-                let mut row_num = 0;
-
-                for row in range.rows() {
-                    row_num += 1;
-
-                    // B열: 날짜 추출
-                    let date_cell = row.get(1);
-                    let date_str = match date_cell {
-                        Some(DataType::String(s)) => Some(s.trim().to_string()),
-                        Some(DataType::Float(f)) => Some(excel_date_to_string(*f)),
-                        Some(DataType::DateTime(dt)) => Some(excel_date_to_string(*dt)),
-                        Some(other_type) if !other_type.is_empty() => {
-                            warn!(
-                                "시트 '{}' 행 {} B열 예상 외 타입: {:?}, 처리 시도 중...",
-                                sheet_name, row_num, other_type
+
+                for (row_idx, row) in range.rows().enumerate().skip(data_start) {
+                    let row_num = start_row as usize + row_idx + 1;
+
+                    // 완료 열 (기본 D열): 완료/취소된 행은 검사 대상 아님
+                    match config
+                        .completion
+                        .classify(cell_at(row, start_col, columns.done))
+                    {
+                        RowStatus::Done => continue,
+                        RowStatus::Skipped => {
+                            debug!(
+                                "시트 '{}' 행 {}: 취소된 항목. 검사 제외.",
+                                sheet_name, row_num
                             );
-                            if let Some(f_val) = other_type.as_f64() {
-                                Some(excel_date_to_string(f_val))
-                            } else {
-                                warn!(
-                                    "시트 '{}' 행 {} B열 {:?} 타입은 날짜로 처리 불가",
-                                    sheet_name, row_num, other_type
-                                );
-                                None
-                            }
+                            continue;
                         }
-                        _ => None,
-                    };
-
-                    // D열: 완료 여부 확인
-                    let completed_cell = row.get(3);
-                    let is_completed = match completed_cell {
-                        Some(DataType::Empty) => false,
-                        Some(DataType::String(s)) if s.trim().is_empty() => false,
-                        Some(_) => true,
-                        None => false,
-                    };
+                        RowStatus::Open => {}
+                    }
 
-                    // --- 조건 1 & 2: 오늘 날짜이고, 완료되지 않았는가? ---
-                    if let Some(date) = date_str {
-                        if date == today_str && !is_completed {
-                            // --- 조건 3 & 4 를 위한 시간 처리 ---
-                            let time_cell = row.get(2);
-                            let time_str_opt = match time_cell {
-                                // Option<String>으로 받기
-                                Some(DataType::String(s)) => Some(s.trim().to_string()),
-                                Some(DataType::Float(f)) => Some(excel_time_to_string(*f)),
-                                Some(DataType::DateTime(dt)) => Some(excel_time_to_string(*dt)),
-                                Some(other_type) if !other_type.is_empty() => {
-                                    warn!(
-                                        "시트 '{}' 행 {} C열 예상 외 타입: {:?}, 처리 시도 중...",
-                                        sheet_name, row_num, other_type
+                    // --- 조건 1 & 2: 완료되지 않았고, 검사 기간 안의 날짜인가? ---
+                    let scheduled = match columns.schedule {
+                        ScheduleColumns::Split { date, time } => {
+                            // 날짜 열 (기본 B열): 날짜 추출 (헤더 등 날짜가 아닌 값은 제외)
+                            let date_col = column_letter(date);
+                            let date = match config
+                                .formats
+                                .cell_to_date(cell_at(row, start_col, date), now.date())
+                            {
+                                Ok(Some(date)) => date,
+                                Ok(None) => continue,
+                                Err(e) => {
+                                    debug!(
+                                        "시트 '{}' 행 {} {}열 날짜 아님: {}",
+                                        sheet_name, row_num, date_col, e
                                     );
-                                    if let Some(f_val) = other_type.as_f64() {
-                                        Some(excel_time_to_string(f_val))
-                                    } else {
-                                        warn!(
-                                            "시트 '{}' 행 {} C열 {:?} 타입은 시간으로 처리 불가",
-                                            sheet_name, row_num, other_type
-                                        );
-                                        None
-                                    }
+                                    continue;
                                 }
-                                _ => None,
                             };
+                            if date < window_start.date() || date > now.date() {
+                                continue;
+                            }
 
-                            if let Some(time_str) = time_str_opt {
-                                // C열 시간 문자열을 NaiveTime으로 파싱 시도
-                                match NaiveTime::parse_from_str(&time_str, "%H:%M:%S") {
-                                    Ok(row_naive_time) => {
-                                        // --- 조건 3: 과거 시간인가? ---
-                                        if row_naive_time < current_naive_time {
-                                            // --- 조건 4: 10분 유예 기간이 지났는가? ---
-                                            let time_difference =
-                                                current_naive_time - row_naive_time;
-                                            if time_difference >= grace_period {
-                                                // 모든 조건 충족! 누락 항목으로 추가
-                                                let missing_entry =
-                                                    format!("{} {}", date, time_str);
-                                                debug!(
-                                                    "  -> 누락 발견 (조건 충족): {}",
-                                                    missing_entry
-                                                );
-                                                current_sheet_missing.push(missing_entry);
-                                            } else {
-                                                // 10분 유예 기간 중, 아직 누락 아님
-                                                debug!(
-                                                    "  -> 누락 건너뜀 (10분 유예 기간): {} {}",
-                                                    date, time_str
-                                                );
-                                            }
-                                        } else {
-                                            // 미래 시간이므로 대상 아님
-                                        }
-                                    }
-                                    Err(e) => {
-                                        // 시간 파싱 실패 시 경고 로그
-                                        warn!(
-                                            "행 {} C열 시간 형식 파싱 오류 '{}': {}",
-                                            row_num, time_str, e
-                                        );
-                                    }
+                            // 시간 열 (기본 C열): 시간 추출
+                            let time_col = column_letter(time);
+                            match config.formats.cell_to_time(cell_at(row, start_col, time)) {
+                                Ok(Some(time)) => date.and_time(time),
+                                Ok(None) => {
+                                    // 시간 열에 시간 정보 자체가 없는 경우 경고
+                                    warn!(
+                                        "시트 '{}' 행 {} {}열에 시간 정보 없음. 누락 검사에서 제외.",
+                                        sheet_name, row_num, time_col
+                                    );
+                                    continue;
+                                }
+                                Err(e) => {
+                                    warn!(
+                                        "시트 '{}' 행 {} {}열 시간 형식 파싱 오류: {}",
+                                        sheet_name, row_num, time_col, e
+                                    );
+                                    continue;
                                 }
-                            } else {
-                                // C열에 시간 정보 자체가 없는 경우 경고
-                                warn!("행 {} C열에 시간 정보 없음. 누락 검사에서 제외.", row_num);
                             }
-                        } // if date == today_str && !is_completed
-                    } // if let Some(date) = date_str
+                        }
+                        ScheduleColumns::Combined(column) => {
+                            // 일시 열: 날짜와 시간을 한 셀에서 추출
+                            match config
+                                .formats
+                                .cell_to_datetime(cell_at(row, start_col, column), now.date())
+                            {
+                                Ok(Some(datetime)) => datetime,
+                                Ok(None) => continue,
+                                Err(e) => {
+                                    debug!(
+                                        "시트 '{}' 행 {} {}열 일시 아님: {}",
+                                        sheet_name,
+                                        row_num,
+                                        column_letter(column),
+                                        e
+                                    );
+                                    continue;
+                                }
+                            }
+                        }
+                    };
+
+                    // --- 조건 3: 검사 기간 안의 과거 시간인가? ---
+                    if scheduled < window_start || scheduled >= now {
+                        // 미래 시간이거나 검사 기간 이전이므로 대상 아님
+                        continue;
+                    }
+
+                    // --- 조건 4: 유예 기간이 지났는가? ---
+                    let time_difference = now - scheduled;
+                    if time_difference < grace_period {
+                        // 유예 기간 중, 아직 누락 아님
+                        debug!(
+                            "  -> 누락 건너뜀 ({}분 유예 기간): {}",
+                            grace_minutes, scheduled
+                        );
+                        continue;
+                    }
+
+                    // 모든 조건 충족! 누락 항목으로 추가
+                    let label = columns
+                        .description
+                        .and_then(|c| cell_at(row, start_col, c))
+                        .map(|cell| cell.to_string())
+                        .map(|d| d.trim().to_string())
+                        .filter(|d| !d.is_empty());
+                    let missing_entry = MissingEntry {
+                        sheet: sheet_name.clone(),
+                        row: row_num,
+                        scheduled,
+                        overdue: time_difference,
+                        label,
+                    };
+                    debug!("  -> 누락 발견 (조건 충족): {}", missing_entry);
+                    current_sheet_missing.push(missing_entry);
                 } // 행 반복 종료
 
                 if !current_sheet_missing.is_empty() {
@@ -158,137 +516,752 @@ fn check_for_missed_notifications(config: &Config) -> Result<NotificationList, B
             } // Ok(range)
             Err(e) => {
                 error!("시트 '{}' 범위 읽기 오류: {}", sheet_name, e);
+                sheet_errors.insert(sheet_name.clone(), format!("시트 범위 읽기 오류: {}", e));
             }
         } // match result
     } // 시트 반복 종료
 
     // 결과 로그 메시지
     if missing_notifications.is_empty() {
-        info!("확인 결과: 조건에 맞는 누락된 알림 항목 없음");
+        info!(
+            "워크북 '{}' 확인 결과: 조건에 맞는 누락된 알림 항목 없음",
+            workbook.name
+        );
     } else {
         let total_missing_count: usize = missing_notifications.values().map(|v| v.len()).sum();
         info!(
-            "확인 결과: 총 {}개의 누락된 알림 항목 발견 ({}개 시트)",
+            "워크북 '{}' 확인 결과: 총 {}개의 누락된 알림 항목 발견 ({}개 시트)",
+            workbook.name,
             total_missing_count,
             missing_notifications.len()
         );
     }
 
-    Ok(missing_notifications)
+    if !sheet_errors.is_empty() {
+        warn!(
+            "워크북 '{}'에서 {}개 시트를 검사하지 못했습니다.",
+            workbook.name,
+            sheet_errors.len()
+        );
+    }
+
+    result.missing = missing_notifications;
+    result.sheet_errors = sheet_errors;
+    result
 }
 
-pub async fn start_notification_service(
+// 다음 예정 시각까지 한 번에 대기하는 최대 시간
+const MAX_SLEEP: Duration = Duration::from_secs(60);
+
+/// 콘솔 `status` 명령으로 출력하는 서비스 상태.
+#[derive(Debug, Default)]
+struct ServiceStatus {
+    last_run: Option<DateTime<Local>>,
+    last_missing_count: Option<usize>,
+    /// 마지막으로 알림을 보냈을 때의 알림 방법별 결과
+    last_deliveries: Vec<Delivery>,
+    /// 실행 중 설정 파일을 마지막으로 다시 읽은 시각과 결과
+    last_reload: Option<(DateTime<Local>, Result<(), String>)>,
+}
+
+/// 누락 검사 1회의 결과.
+#[derive(Debug, Default)]
+struct CheckSummary {
+    missing_count: usize,
+    /// 보낸 알림의 알림 방법별 결과. 알리지 않았으면 비어 있음
+    deliveries: Vec<Delivery>,
+}
+
+// 서비스가 읽고 쓰는 파일 경로. 모두 실행 파일 폴더 기준
+#[derive(Debug, Clone)]
+struct ServicePaths {
+    output: PathBuf,
+    alert_state: PathBuf,
+}
+
+impl ServicePaths {
+    fn new(exe_dir: &Path) -> Self {
+        ServicePaths {
+            output: exe_dir.join(OUTPUT_FILE_NAME),
+            alert_state: exe_dir.join(ALERT_STATE_FILE_NAME),
+        }
+    }
+}
+
+/// 누락 검사를 시작한 이유.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckTrigger {
+    /// 실행 일정
+    Scheduled,
+    /// 콘솔 check 명령. 이미 알린 항목도 다시 알림
+    Manual,
+    /// 검사 대상 파일 저장
+    FileChanged,
+}
+
+/// 알림 단계 하나로 보낼 알림.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Notification {
+    tier: String,
+    channels: Vec<String>,
+    alert: Alert,
+}
+
+// 검사 결과와 알림 상태 변화로 알림 단계별 알림 생성
+//
+// 알릴 항목은 각 항목의 알림 단계로 묶고, 처리됨/데이터 지연 안내는 가장 낮은 단계로 보냅니다.
+fn build_notifications(
     config: &Config,
-    exe_dir: &PathBuf,
-) -> Result<(), Box<dyn Error>> {
-    info!("알림 확인 서비스 시작. 매시간 11, 26, 41, 56분에 실행됩니다.");
-    let output_path = exe_dir.join(OUTPUT_FILE_NAME);
-    let notification_exe_path = exe_dir.join("notification.exe");
+    result: &CheckResult,
+    update: &AlertUpdate,
+    alerts: &AlertState,
+    trigger: CheckTrigger,
+    now: DateTime<Local>,
+) -> Vec<Notification> {
+    let total_missing_count = result.missing_count();
 
-    loop {
-        let now = Local::now();
-        let current_minute = now.minute();
+    // 단계 번호 -> 알릴 항목. check 명령이면 알림 중인 항목 전체
+    let mut groups: BTreeMap<usize, Vec<&AlertRecord>> = BTreeMap::new();
+    let announced: Vec<&AlertRecord> = if trigger == CheckTrigger::Manual {
+        alerts.records().iter().collect()
+    } else {
+        update.announced().collect()
+    };
+    for record in announced {
+        groups.entry(record.tier).or_default().push(record);
+    }
 
-        let trigger_check = match current_minute {
-            11 | 26 | 41 | 56 => true,
-            _ => false,
-        };
+    let mut extra_lines = Vec::new();
+    if !update.resolved.is_empty() {
+        extra_lines.push(RESOLVED_MESSAGE.replace("{count}", &update.resolved.len().to_string()));
+    }
+    // 오래된 스냅샷으로 검사한 워크북은 누락이 없어도 알림에 표시
+    let stale: Vec<String> = result
+        .stale_workbooks()
+        .filter_map(|w| {
+            w.fallback
+                .as_ref()
+                .map(|f| format!("{}({}분 전)", w.name, f.age.num_minutes()))
+        })
+        .collect();
+    if !stale.is_empty() {
+        extra_lines.push(format!(
+            "원본을 읽지 못해 오래된 데이터로 검사했습니다: {}",
+            stale.join(", ")
+        ));
+    }
+    if !extra_lines.is_empty() {
+        groups.entry(0).or_default();
+    }
 
-        if trigger_check {
-            info!(
-                "현재 시간: {}, 실행 조건 충족. 누락 항목 검사 시작...",
-                now.format("%H:%M:%S")
+    groups
+        .into_iter()
+        .map(|(index, records)| {
+            let tier = config.escalation.tier(index);
+            let entries = alert_entries(result, &records);
+            let context = TemplateContext {
+                count: records.len(),
+                new: records.iter().filter(|r| update.new.contains(r)).count(),
+                total: total_missing_count,
+                now,
+                entries: &entries,
+            };
+            let mut lines = Vec::new();
+            if !records.is_empty() {
+                let message = tier
+                    .message
+                    .as_ref()
+                    .unwrap_or(&config.notification_message_template);
+                lines.push(message.render(&context));
+            }
+            if index == 0 {
+                lines.append(&mut extra_lines);
+            }
+            let title = tier
+                .title
+                .as_ref()
+                .unwrap_or(&config.notification_title)
+                .render(&context);
+            Notification {
+                tier: tier.name.clone(),
+                channels: tier.channels.clone(),
+                alert: Alert {
+                    title,
+                    message: lines.join("\n"),
+                    entries,
+                },
+            }
+        })
+        .collect()
+}
+
+// 알림 상태 항목에 해당하는 이번 검사의 누락 항목. 워크북 설정 순서, 시트 이름, 시트 안의 행 순서로 정렬
+fn alert_entries(result: &CheckResult, records: &[&AlertRecord]) -> Vec<AlertEntry> {
+    let mut entries = Vec::new();
+    for workbook in &result.workbooks {
+        let mut sheets: Vec<&String> = workbook.missing.keys().collect();
+        sheets.sort();
+        for sheet in sheets {
+            entries.extend(
+                workbook.missing[sheet]
+                    .iter()
+                    .filter(|e| {
+                        records.iter().any(|r| {
+                            r.workbook == workbook.name
+                                && r.sheet == e.sheet
+                                && r.row == e.row
+                                && r.scheduled == e.scheduled
+                        })
+                    })
+                    .map(|e| AlertEntry {
+                        workbook: workbook.name.clone(),
+                        entry: e.clone(),
+                    }),
             );
-            match check_for_missed_notifications(config) {
-                Ok(notification_list) => {
-                    if !notification_list.is_empty() {
-                        let total_missing_count: usize =
-                            notification_list.values().map(|v| v.len()).sum();
-                        info!(
-                            "{}개 시트에서 총 {}개의 누락된 항목 발견.",
-                            notification_list.len(),
-                            total_missing_count
-                        );
-                        for (sheet, entries) in &notification_list {
-                            let entries_str = entries.join(", ");
-                            info!("  - 시트 [{}]: {}", sheet, entries_str);
-                        }
+        }
+    }
+    entries
+}
 
-                        if let Err(e) = write_missing_report(&output_path, &notification_list) {
-                            error!("missing.txt 파일 쓰기 실패: {}", e);
-                        } else {
-                            info!("누락 목록을 {} 에 저장했습니다.", output_path.display());
-                        }
+// 누락 검사 1회 실행: 검사, 알림 상태 갱신, 보고서 작성, 알림 실행까지 처리하고 누락 개수와 알림 결과를 반환
+//
+// 새로 누락된 항목, 재알림 주기가 지난 항목, 처리된 항목이 있을 때만 알립니다.
+fn run_check(
+    config: &Config,
+    clock: &dyn Clock,
+    paths: &ServicePaths,
+    notifiers: &NotifierRegistry,
+    alerts: &mut AlertState,
+    trigger: CheckTrigger,
+) -> Result<CheckSummary, Box<dyn Error>> {
+    let result = check_for_missed_notifications(config, clock, SnapshotMode::Update)?;
+
+    for workbook in &result.workbooks {
+        for (sheet, e) in &workbook.sheet_errors {
+            error!("  - [{}] 시트 [{}] 검사 실패: {}", workbook.name, sheet, e);
+        }
+    }
+    let has_errors = result
+        .workbooks
+        .iter()
+        .any(|w| w.error.is_some() || !w.sheet_errors.is_empty());
+    let has_stale = result.stale_workbooks().next().is_some();
+    let total_missing_count = result.missing_count();
+
+    let reminder =
+        (config.reminder_minutes > 0).then(|| ChronoDuration::minutes(config.reminder_minutes));
+    let update = alerts.update(&result, clock.now(), reminder, &config.escalation);
+    if let Err(e) = alerts.save(&paths.alert_state) {
+        error!(
+            "알림 상태 파일 저장 실패: {}: {}",
+            paths.alert_state.display(),
+            e
+        );
+    }
+
+    if total_missing_count > 0 {
+        info!("총 {}개의 누락된 항목 발견.", total_missing_count);
+        for workbook in &result.workbooks {
+            for (sheet, entries) in &workbook.missing {
+                let entries_str: Vec<String> = entries.iter().map(|e| e.to_string()).collect();
+                info!(
+                    "  - [{}] 시트 [{}]: {}",
+                    workbook.name,
+                    sheet,
+                    entries_str.join(", ")
+                );
+            }
+        }
+    }
+    for record in &update.resolved {
+        info!(
+            "  - 처리됨: [{}] 시트 [{}] {}행 {}",
+            record.workbook,
+            record.sheet,
+            record.row,
+            record.scheduled.format("%Y-%m-%d %H:%M:%S")
+        );
+    }
 
-                        let title = config.notification_title.as_deref().unwrap_or("알림");
+    // 처리된 항목이 있으면 이전 보고서가 남지 않도록 새로 작성
+    if total_missing_count > 0 || has_errors || has_stale || !update.resolved.is_empty() {
+        if let Err(e) = write_missing_report(&paths.output, &result) {
+            error!("missing.txt 파일 쓰기 실패: {}", e);
+        } else if total_missing_count > 0 {
+            info!("누락 목록을 {} 에 저장했습니다.", paths.output.display());
+        }
+    }
 
-                        let message_template = config
-                            .notification_message_template
-                            .as_deref()
-                            .unwrap_or("{count}개의 누락된 데이터가 존재합니다!");
-                        let message =
-                            message_template.replace("{count}", &total_missing_count.to_string());
+    let notify = update.should_notify()
+        || has_stale
+        || (trigger == CheckTrigger::Manual && total_missing_count > 0);
+    if !notify {
+        if total_missing_count > 0 {
+            info!("새로 누락된 항목이 없습니다. 알림을 다시 보내지 않습니다.");
+        }
+        return Ok(CheckSummary {
+            missing_count: total_missing_count,
+            deliveries: Vec::new(),
+        });
+    }
 
-                        info!("알림 실행: Title='{}', Message='{}'", title, message);
+    let deliveries = build_notifications(config, &result, &update, alerts, trigger, clock.now())
+        .iter()
+        .flat_map(|notification| dispatch(notification, notifiers))
+        .collect();
 
-                        if notification_exe_path.exists() {
-                            match Command::new(notification_exe_path.clone())
-                                .arg("--title")
-                                .arg(title)
-                                .arg("--message")
-                                .arg(&message)
-                                .status()
-                            {
-                                Ok(status) => {
-                                    if status.success() {
-                                        info!("notification.exe 실행 성공.");
-                                    } else {
-                                        warn!(
-                                            "notification.exe 실행 완료되었으나, 성공 상태가 아님: {:?}",
-                                            status.code()
-                                        );
+    Ok(CheckSummary {
+        missing_count: total_missing_count,
+        deliveries,
+    })
+}
+
+// 알림 단계에 설정된 알림 방법으로 전송하고 각각의 결과를 기록
+fn dispatch(notification: &Notification, notifiers: &NotifierRegistry) -> Vec<Delivery> {
+    info!(
+        "알림 단계 '{}' ({}) 알림 전송",
+        notification.tier,
+        notification.channels.join(", ")
+    );
+    let deliveries = notifiers.send(&notification.channels, &notification.alert);
+    log_deliveries(&deliveries);
+    deliveries
+}
+
+fn log_deliveries(deliveries: &[Delivery]) {
+    for delivery in deliveries {
+        match &delivery.result {
+            Ok(()) => info!("'{}' 알림 전송 성공.", delivery.notifier),
+            Err(e) => error!("'{}' 알림 전송 실패: {}", delivery.notifier, e),
+        }
+    }
+}
+
+// 블로킹 스레드에서 실행하는 검사가 함께 쓰는 상태. 설정을 다시 읽으면 config와 notifiers만 교체
+#[derive(Clone)]
+struct CheckContext {
+    config: Arc<Config>,
+    notifiers: Arc<NotifierRegistry>,
+    clock: Arc<dyn Clock>,
+    paths: ServicePaths,
+    alerts: Arc<Mutex<AlertState>>,
+}
+
+/// 블로킹 스레드에서 끝난 누락 검사 1회.
+#[derive(Debug)]
+struct FinishedCheck {
+    started: DateTime<Local>,
+    result: Result<CheckSummary, String>,
+}
+
+// 진행 중인 누락 검사. 검사 중에 들어온 요청은 하나로 모아 검사가 끝난 뒤 실행
+#[derive(Debug, Default)]
+struct CheckQueue {
+    running: Option<JoinHandle<FinishedCheck>>,
+    pending: Option<CheckTrigger>,
+}
+
+impl CheckQueue {
+    // 진행 중인 검사가 없으면 바로 시작하고, 있으면 끝난 뒤 다시 검사
+    fn request(&mut self, trigger: CheckTrigger, context: &CheckContext) {
+        if self.running.is_none() {
+            self.running = Some(spawn_check(context.clone(), trigger));
+            return;
+        }
+        info!("이전 검사가 아직 진행 중입니다. 끝나면 다시 검사합니다.");
+        // check 명령은 이미 알린 항목도 다시 알리므로 우선
+        self.pending = Some(match self.pending {
+            Some(CheckTrigger::Manual) => CheckTrigger::Manual,
+            _ => trigger,
+        });
+    }
+
+    // 검사가 끝났을 때 호출. 기다리던 요청이 있으면 시작
+    fn finish(&mut self, context: &CheckContext) {
+        self.running = None;
+        if let Some(trigger) = self.pending.take() {
+            self.request(trigger, context);
+        }
+    }
+}
+
+// 누락 검사를 블로킹 스레드에서 실행. 원본 파일 읽기 재시도와 알림 전송 중에도 콘솔 명령과 일정을 처리할 수 있음
+fn spawn_check(context: CheckContext, trigger: CheckTrigger) -> JoinHandle<FinishedCheck> {
+    task::spawn_blocking(move || {
+        let started = context.clock.now();
+        let mut alerts = context
+            .alerts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let result = run_check(
+            &context.config,
+            context.clock.as_ref(),
+            &context.paths,
+            &context.notifiers,
+            &mut alerts,
+            trigger,
+        )
+        .map_err(|e| e.to_string());
+        FinishedCheck { started, result }
+    })
+}
+
+// 끝난 검사 결과를 상태에 반영
+fn record_check(status: &mut ServiceStatus, finished: Result<FinishedCheck, JoinError>) {
+    let finished = match finished {
+        Ok(finished) => finished,
+        Err(e) => {
+            error!("누락 검사 작업이 비정상 종료되었습니다: {}", e);
+            return;
+        }
+    };
+    status.last_run = Some(finished.started);
+    match finished.result {
+        Ok(summary) => {
+            status.last_missing_count = Some(summary.missing_count);
+            if !summary.deliveries.is_empty() {
+                status.last_deliveries = summary.deliveries;
+            }
+        }
+        Err(e) => error!("알림 확인 중 오류 발생: {}", e),
+    }
+}
+
+fn print_status(status: &ServiceStatus, scheduler: &Scheduler, checking: bool, clock: &dyn Clock) {
+    let now = clock.now();
+    let last_run = status
+        .last_run
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "없음".to_string());
+    let last_missing = status
+        .last_missing_count
+        .map(|c| format!("{}개", c))
+        .unwrap_or_else(|| "알 수 없음".to_string());
+    println!("현재 시각        : {}", now.format("%Y-%m-%d %H:%M:%S"));
+    println!("마지막 실행      : {}", last_run);
+    if checking {
+        println!("검사 진행 중     : 예");
+    }
+    let next_trigger = scheduler
+        .next_fire(&now)
+        .map(|next| next.at.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "없음".to_string());
+    println!(
+        "다음 실행 예정   : {} ({})",
+        next_trigger,
+        scheduler.schedule()
+    );
+    println!("마지막 누락 개수 : {}", last_missing);
+    if let Some((at, result)) = &status.last_reload {
+        let result = match result {
+            Ok(()) => "적용".to_string(),
+            Err(e) => format!("거부 ({})", e),
+        };
+        println!(
+            "설정 다시 읽음   : {} {}",
+            at.format("%Y-%m-%d %H:%M:%S"),
+            result
+        );
+    }
+    if status.last_deliveries.is_empty() {
+        println!("마지막 알림 전송 : 없음");
+    }
+    for delivery in &status.last_deliveries {
+        let result = match &delivery.result {
+            Ok(()) => "성공".to_string(),
+            Err(e) => format!("실패 ({})", e),
+        };
+        println!(
+            "마지막 알림 전송 : [{}] '{}' {}",
+            delivery.notifier, delivery.title, result
+        );
+    }
+}
+
+// 검사 대상 파일 감시 시작. [watch]에서 끈 경우 None
+fn watch_workbooks(config: &Config) -> Result<Option<Receiver<PathBuf>>, Box<dyn Error>> {
+    if !config.watch.enabled {
+        return Ok(None);
+    }
+    let paths = config.workbooks.iter().map(|w| w.path.clone()).collect();
+    info!(
+        "파일 변경 감지 시작 (확인 간격 {}초, 저장 후 {}초 대기)",
+        config.watch.poll_interval.as_secs(),
+        config.watch.debounce.as_secs()
+    );
+    Ok(Some(spawn_watcher(paths, &config.watch)?))
+}
+
+// 설정 파일을 다시 읽고 알림 방법까지 준비. 하나라도 실패하면 적용하지 않음
+fn load_new_config(
+    source: &ConfigSource,
+    exe_dir: &Path,
+) -> Result<(Config, NotifierRegistry), String> {
+    let config = source.load().map_err(|e| e.to_string())?;
+    if config.workbooks.iter().all(|w| !w.path.exists()) {
+        let paths: Vec<String> = config
+            .workbooks
+            .iter()
+            .map(|w| w.path.display().to_string())
+            .collect();
+        return Err(format!(
+            "설정된 Excel 파일을 하나도 찾을 수 없습니다: {}",
+            paths.join(", ")
+        ));
+    }
+    let notifiers = NotifierRegistry::from_configs(&config.notifiers, exe_dir)?;
+    Ok((config, notifiers))
+}
+
+// 바뀐 설정 항목을 로그에 남김
+fn log_config_changes(old: &Config, new: &Config) {
+    let old_lines = describe_config(old);
+    let new_lines = describe_config(new);
+    let removed: Vec<&String> = old_lines
+        .iter()
+        .filter(|l| !new_lines.contains(l))
+        .collect();
+    let added: Vec<&String> = new_lines
+        .iter()
+        .filter(|l| !old_lines.contains(l))
+        .collect();
+    if removed.is_empty() && added.is_empty() {
+        info!("설정 파일이 저장되었지만 바뀐 설정은 없습니다.");
+        return;
+    }
+    for line in removed {
+        info!("설정 변경 전: {}", line);
+    }
+    for line in added {
+        info!("설정 변경 후: {}", line);
+    }
+}
+
+/// 주기적으로 누락 항목을 검사하고 알리는 서비스. 콘솔에서 `quit`을 입력하면 끝납니다.
+///
+/// 실행 중 설정 파일이 저장되면 설정을 다시 읽습니다. 새 설정이 올바르면 다음 검사부터
+/// 한꺼번에 적용하고, 오류가 있으면 무엇이 잘못됐는지 로그에 남기고 이전 설정을 계속 사용합니다.
+///
+/// 누락 검사는 블로킹 스레드에서 실행하므로 검사 중에도 콘솔 명령을 처리합니다.
+pub async fn start_notification_service(
+    config: Config,
+    source: &ConfigSource,
+    exe_dir: &Path,
+    clock: Arc<dyn Clock>,
+) -> Result<(), Box<dyn Error>> {
+    info!(
+        "알림 확인 서비스 시작. 실행 일정: {}, 유예 시간: {}분",
+        config.schedule, config.grace_minutes
+    );
+    let paths = ServicePaths::new(exe_dir);
+    let notifiers = NotifierRegistry::from_configs(&config.notifiers, exe_dir)?;
+    let mut status = ServiceStatus::default();
+    let alerts = AlertState::load(&paths.alert_state);
+    info!(
+        "알림 상태 불러옴: {} (알림 중인 항목 {}개)",
+        paths.alert_state.display(),
+        alerts.open_count()
+    );
+    let mut scheduler = Scheduler::new(config.schedule.clone());
+    let mut context = CheckContext {
+        config: Arc::new(config),
+        notifiers: Arc::new(notifiers),
+        clock: clock.clone(),
+        paths,
+        alerts: Arc::new(Mutex::new(alerts)),
+    };
+    let mut checks = CheckQueue::default();
+    // 일일 요약 등 정해진 시각의 알림. 메일 전송이 런타임을 막지 않도록 블로킹 스레드에서 처리
+    let mut polling: Option<JoinHandle<Vec<Delivery>>> = None;
+    let mut announced_slot = None;
+
+    let mut commands = spawn_console()?;
+    let mut console_open = true;
+    info!("명령 입력 대기 중. 'help'를 입력하면 사용 가능한 명령을 볼 수 있습니다.");
+
+    let mut changes = watch_workbooks(&context.config)?;
+    // 설정 파일은 [watch] 설정과 관계없이 항상 감시
+    let mut config_changes = Some(spawn_watcher(
+        vec![source.path.clone()],
+        &WatchSettings::default(),
+    )?);
+    info!("설정 파일 변경 감지 시작: {}", source.path.display());
+
+    loop {
+        let now = clock.now();
+        if polling.is_none() {
+            let notifiers = context.notifiers.clone();
+            polling = Some(task::spawn_blocking(move || notifiers.poll(now)));
+        }
+        let next_fire = scheduler.next_fire(&now);
+
+        if let Some(next) = &next_fire
+            && next.at <= now
+        {
+            info!(
+                "현재 시간: {}, 예정 시각 {} 도래. 누락 항목 검사 시작...",
+                now.format("%H:%M:%S"),
+                next.slot.format("%H:%M")
+            );
+            checks.request(CheckTrigger::Scheduled, &context);
+            scheduler.mark_fired(next.slot, &now);
+            continue;
+        }
+
+        // 다음 예정 시각까지 대기. 대기 중 시스템 시계가 바뀌어도 반영되도록 최대 MAX_SLEEP 단위로 나눠서 대기
+        let next_slot = next_fire.as_ref().map(|next| next.slot);
+        let announce = announced_slot != Some(next_slot);
+        announced_slot = Some(next_slot);
+        let sleep_duration = match &next_fire {
+            Some(next) => {
+                if announce {
+                    info!(
+                        "다음 확인 시간까지 대기합니다: {}",
+                        next.at.format("%Y-%m-%d %H:%M:%S")
+                    );
+                }
+                (next.at - now).to_std().unwrap_or_default().min(MAX_SLEEP)
+            }
+            None => {
+                if announce {
+                    warn!(
+                        "실행 일정({})에 예정된 다음 실행 시각이 없습니다.",
+                        scheduler.schedule()
+                    );
+                }
+                MAX_SLEEP
+            }
+        };
+
+        // 대기 중에도 콘솔 명령과 파일 변경을 처리. 처리 후에는 남은 대기 시간을 이어서 기다림
+        let wake_at = Instant::now() + sleep_duration;
+        loop {
+            let command = tokio::select! {
+                _ = sleep_until(wake_at) => break,
+                command = commands.recv(), if console_open => command,
+                finished = next_finished(&mut checks.running) => {
+                    record_check(&mut status, finished);
+                    checks.finish(&context);
+                    continue;
+                }
+                deliveries = next_finished(&mut polling) => {
+                    polling = None;
+                    match deliveries {
+                        Ok(deliveries) if !deliveries.is_empty() => {
+                            log_deliveries(&deliveries);
+                            status.last_deliveries = deliveries;
+                        }
+                        Ok(_) => {}
+                        Err(e) => error!("정해진 시각의 알림 처리 작업이 비정상 종료되었습니다: {}", e),
+                    }
+                    continue;
+                }
+                path = next_change(&mut config_changes) => {
+                    if path.is_none() {
+                        warn!("설정 파일 변경 감지가 중단되었습니다.");
+                        config_changes = None;
+                        continue;
+                    }
+                    info!("설정 파일 저장됨. 설정을 다시 읽습니다: {}", source.path.display());
+                    let result = match load_new_config(source, exe_dir) {
+                        Ok((new_config, new_notifiers)) => {
+                            let config = &context.config;
+                            log_config_changes(config, &new_config);
+                            if new_config.schedule != config.schedule {
+                                scheduler.set_schedule(new_config.schedule.clone());
+                            }
+                            let workbooks_changed = new_config.watch != config.watch
+                                || new_config
+                                    .workbooks
+                                    .iter()
+                                    .map(|w| &w.path)
+                                    .ne(config.workbooks.iter().map(|w| &w.path));
+                            // 진행 중인 검사는 이전 설정으로 끝까지 실행
+                            context.config = Arc::new(new_config);
+                            context.notifiers = Arc::new(new_notifiers);
+                            if workbooks_changed {
+                                // 이전 감시 스레드는 채널이 닫히면 끝남
+                                changes = match watch_workbooks(&context.config) {
+                                    Ok(changes) => changes,
+                                    Err(e) => {
+                                        warn!("파일 변경 감지를 다시 시작하지 못했습니다: {}", e);
+                                        None
                                     }
-                                }
-                                Err(e) => {
-                                    error!("notification.exe 실행 실패: {}", e);
-                                }
+                                };
                             }
-                        } else {
-                            warn!(
-                                "notification.exe 파일을 찾을 수 없습니다: {}",
-                                notification_exe_path.display()
+                            info!("새 설정을 적용했습니다.");
+                            Ok(())
+                        }
+                        Err(e) => {
+                            error!(
+                                "설정 파일 오류로 변경 사항을 적용하지 않고 이전 설정을 계속 사용합니다: {}",
+                                e
                             );
+                            Err(e)
                         }
-                    }
+                    };
+                    status.last_reload = Some((clock.now(), result));
+                    // 실행 일정이 바뀌었을 수 있으므로 다음 실행 시각을 다시 계산
+                    break;
                 }
-                Err(e) => {
-                    error!("알림 확인 중 오류 발생: {}", e);
-                    sleep(Duration::from_secs(60)).await;
+                path = next_change(&mut changes) => {
+                    match path {
+                        Some(path) => {
+                            info!("{} 저장됨. 누락 항목 다시 검사...", path.display());
+                            checks.request(CheckTrigger::FileChanged, &context);
+                        }
+                        None => {
+                            warn!("파일 변경 감지가 중단되었습니다.");
+                            changes = None;
+                        }
+                    }
                     continue;
                 }
-            }
-            info!("다음 확인 시간까지 대기합니다 (약 65초 후 재검사)...");
-            sleep(Duration::from_secs(65)).await;
-        } else {
-            let seconds_until_next_minute = 60 - now.second();
-            let sleep_duration_secs = if current_minute == 10
-                || current_minute == 25
-                || current_minute == 40
-                || current_minute == 55
-            {
-                1
-            } else {
-                (seconds_until_next_minute % 60).max(1)
             };
-            sleep(Duration::from_secs(sleep_duration_secs as u64)).await;
+
+            match command {
+                Some(ConsoleCommand::Quit) => {
+                    info!("quit 명령 수신. 알림 확인 서비스를 종료합니다.");
+                    if let Some(running) = checks.running.take() {
+                        // 알림 상태 파일 저장이 끊기지 않도록 진행 중인 검사는 끝까지 기다림
+                        info!("진행 중인 검사가 끝나면 종료합니다.");
+                        record_check(&mut status, running.await);
+                    }
+                    if let Some(polling) = polling.take()
+                        && let Ok(deliveries) = polling.await
+                    {
+                        log_deliveries(&deliveries);
+                    }
+                    return Ok(());
+                }
+                Some(ConsoleCommand::Check) => {
+                    info!("check 명령 수신. 즉시 누락 항목 검사 시작...");
+                    checks.request(CheckTrigger::Manual, &context);
+                }
+                Some(ConsoleCommand::Status) => print_status(
+                    &status,
+                    &scheduler,
+                    checks.running.is_some(),
+                    clock.as_ref(),
+                ),
+                None => console_open = false,
+            }
         }
     }
-    // TODO: 기능 수행 대기 시간동안, command 를 입력받을 수 있게 해야함.
-    // 1. quit: 종료
-    // 2. check: 즉시 확인
-    // 3. status: 현재 상태 확인
-    // 4. help: 도움말 출력
-    // 5. log: 로그 출력 (로그 레벨 조정 필요)
-    // 근데 따로 job spawn 하고.. command parsing 받아야 하고.. 귀찬다
-    // Ok(())
+}
+
+// 블로킹 스레드 작업이 끝나기를 기다림. 진행 중인 작업이 없으면 끝나지 않음
+async fn next_finished<T>(running: &mut Option<JoinHandle<T>>) -> Result<T, JoinError> {
+    match running {
+        Some(running) => running.await,
+        None => std::future::pending().await,
+    }
+}
+
+// 파일 변경 알림 대기. 감시하지 않으면 끝나지 않음
+async fn next_change(changes: &mut Option<Receiver<PathBuf>>) -> Option<PathBuf> {
+    match changes {
+        Some(changes) => changes.recv().await,
+        None => std::future::pending().await,
+    }
 }
@@ -0,0 +1,539 @@
+// src/notifier.rs
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::Duration,
+};
+
+use chrono::{DateTime, Duration as ChronoDuration, Local};
+use tracing::{info, warn};
+
+use crate::{
+    consts::{
+        CONSOLE_NOTIFIER, DEFAULT_HTTP_TIMEOUT_SECS, DEFAULT_NOTIFY_BACKOFF_MS,
+        DEFAULT_NOTIFY_RETRIES, DIGEST_FILE_PREFIX, NOTIFICATION_EXE_NAME, TOAST_NOTIFIER,
+    },
+    email::{EmailNotifier, EmailSettings},
+    notification::MissingEntry,
+    snapshot::safe_file_name,
+    teams::{CardFormat, TeamsNotifier},
+    webhook::{BodyTemplate, WebhookNotifier, url_origin},
+};
+
+/// 알림 하나의 내용.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Alert {
+    pub title: String,
+    pub message: String,
+    /// 알리는 누락 항목. 워크북, 시트 순으로 정렬되어 있습니다.
+    pub entries: Vec<AlertEntry>,
+}
+
+/// 알림에 포함된 누락 항목 하나.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertEntry {
+    pub workbook: String,
+    pub entry: MissingEntry,
+}
+
+impl Alert {
+    /// 누락 항목을 (워크북, 시트)별로 묶음. 순서는 유지합니다.
+    pub fn by_sheet(&self) -> Vec<((&str, &str), Vec<&MissingEntry>)> {
+        let mut groups: Vec<((&str, &str), Vec<&MissingEntry>)> = Vec::new();
+        for item in &self.entries {
+            let key = (item.workbook.as_str(), item.entry.sheet.as_str());
+            match groups.last_mut() {
+                Some((last, entries)) if *last == key => entries.push(&item.entry),
+                _ => groups.push((key, vec![&item.entry])),
+            }
+        }
+        groups
+    }
+}
+
+/// 지연 시간을 "1시간 5분" 형식으로 표시.
+pub fn format_duration(duration: ChronoDuration) -> String {
+    let minutes = duration.num_minutes().max(0);
+    if minutes < 60 {
+        format!("{}분", minutes)
+    } else if minutes % 60 == 0 {
+        format!("{}시간", minutes / 60)
+    } else {
+        format!("{}시간 {}분", minutes / 60, minutes % 60)
+    }
+}
+
+/// 지연 시간을 "1시간 5분 지연" 형식으로 표시.
+pub fn format_overdue(overdue: ChronoDuration) -> String {
+    format!("{} 지연", format_duration(overdue))
+}
+
+/// 알림 전송 방법.
+pub trait Notifier: Send + Sync {
+    /// 알림 전송. 실패하면 이유를 반환합니다.
+    fn notify(&self, alert: &Alert) -> Result<(), String>;
+
+    /// 정해진 시각에 보내는 알림(일일 요약 등) 처리. 보냈으면 제목과 결과를 반환합니다.
+    fn poll(&self, _now: DateTime<Local>) -> Option<(String, Result<(), String>)> {
+        None
+    }
+}
+
+/// 전송 1회의 실패.
+#[derive(Debug)]
+pub struct SendError {
+    pub message: String,
+    /// 다시 시도하면 성공할 수 있는 실패 (연결 실패, 5xx 응답 등)
+    pub retryable: bool,
+}
+
+/// 네트워크 알림의 재시도 설정.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// 첫 시도 후 다시 시도하는 횟수
+    pub retries: u32,
+    /// 첫 재시도 전 대기 시간. 재시도마다 두 배로 늘어남
+    pub backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            retries: DEFAULT_NOTIFY_RETRIES,
+            backoff: Duration::from_millis(DEFAULT_NOTIFY_BACKOFF_MS),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// `send`를 성공하거나 다시 시도할 수 없는 실패가 날 때까지 실행.
+    pub fn run(
+        &self,
+        label: &str,
+        mut send: impl FnMut() -> Result<(), SendError>,
+    ) -> Result<(), String> {
+        let mut backoff = self.backoff;
+        let mut attempt = 0;
+        loop {
+            match send() {
+                Ok(()) => return Ok(()),
+                Err(e) if e.retryable && attempt < self.retries => {
+                    attempt += 1;
+                    warn!(
+                        "{} 전송 실패, {}ms 후 다시 시도 ({}/{}): {}",
+                        label,
+                        backoff.as_millis(),
+                        attempt,
+                        self.retries,
+                        e.message
+                    );
+                    thread::sleep(backoff);
+                    backoff *= 2;
+                }
+                Err(e) if attempt > 0 => {
+                    return Err(format!("{} ({}회 시도)", e.message, attempt + 1));
+                }
+                Err(e) => return Err(e.message),
+            }
+        }
+    }
+
+    /// `retries`, `backoff_ms` 항목 적용. 알 수 없는 항목이면 Ok(false).
+    pub fn apply(&mut self, key: &str, value: &str) -> Result<bool, String> {
+        match key {
+            "retries" => {
+                self.retries = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("0 이상의 정수여야 합니다: '{}'", value))?
+            }
+            "backoff_ms" => {
+                self.backoff = value
+                    .trim()
+                    .parse()
+                    .map(Duration::from_millis)
+                    .map_err(|_| format!("0 이상의 밀리초(정수)여야 합니다: '{}'", value))?
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// `notification.exe --title <제목> --message <메시지>` 형식의 외부 프로그램으로 알림.
+pub struct ExeNotifier {
+    path: PathBuf,
+}
+
+impl ExeNotifier {
+    pub fn new(path: PathBuf) -> Self {
+        ExeNotifier { path }
+    }
+}
+
+impl Notifier for ExeNotifier {
+    fn notify(&self, alert: &Alert) -> Result<(), String> {
+        if !self.path.exists() {
+            return Err(format!("파일을 찾을 수 없습니다: {}", self.path.display()));
+        }
+        let status = Command::new(&self.path)
+            .arg("--title")
+            .arg(&alert.title)
+            .arg("--message")
+            .arg(&alert.message)
+            .status()
+            .map_err(|e| format!("{} 실행 실패: {}", self.path.display(), e))?;
+        if !status.success() {
+            return Err(format!(
+                "{} 실행 완료되었으나, 성공 상태가 아님: {:?}",
+                self.path.display(),
+                status.code()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 임의의 명령으로 알림. 인자의 `{title}`, `{message}`를 알림 내용으로 바꿉니다.
+///
+/// 셸을 거치지 않고 인자를 그대로 넘기므로 메시지에 따옴표나 특수 문자가 있어도 안전합니다.
+pub struct CommandNotifier {
+    program: String,
+    args: Vec<String>,
+}
+
+impl CommandNotifier {
+    pub fn new(program: String, args: Vec<String>) -> Self {
+        CommandNotifier { program, args }
+    }
+}
+
+impl Notifier for CommandNotifier {
+    fn notify(&self, alert: &Alert) -> Result<(), String> {
+        let args = self.args.iter().map(|arg| {
+            arg.replace("{title}", &alert.title)
+                .replace("{message}", &alert.message)
+        });
+        let status = Command::new(&self.program)
+            .args(args)
+            .status()
+            .map_err(|e| format!("'{}' 실행 실패: {}", self.program, e))?;
+        if !status.success() {
+            return Err(format!(
+                "'{}' 실행 완료되었으나, 성공 상태가 아님: {:?}",
+                self.program,
+                status.code()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 콘솔(표준 출력)에 알림 내용을 출력.
+pub struct ConsoleNotifier;
+
+impl Notifier for ConsoleNotifier {
+    fn notify(&self, alert: &Alert) -> Result<(), String> {
+        println!("[{}] {}", alert.title, alert.message);
+        Ok(())
+    }
+}
+
+/// `[notifier:<이름>]` 섹션의 알림 방법 설정.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotifierKind {
+    /// `type = exe`, `path = ...` (생략하면 실행 파일 폴더의 notification.exe)
+    Exe { path: Option<PathBuf> },
+    /// `type = command`, `command = ...`, `arg = ...` (여러 줄, `args`도 허용)
+    Command { program: String, args: Vec<String> },
+    /// `type = console`
+    Console,
+    /// `type = teams`, `url = ...`, `card = adaptive|message`, `retries`, `backoff_ms`, `timeout_seconds`
+    Teams {
+        url: String,
+        card: CardFormat,
+        retry: RetryPolicy,
+        timeout: Duration,
+    },
+    /// `type = webhook`, `url = ...`, `header = 이름: 값` (여러 줄, `headers`도 허용), `body = ...`,
+    /// `retries`, `backoff_ms`, `timeout_seconds`
+    Webhook {
+        url: String,
+        headers: Vec<(String, String)>,
+        body: Option<BodyTemplate>,
+        retry: RetryPolicy,
+        timeout: Duration,
+    },
+    /// `type = email`. 항목은 [`EmailSettings`] 참고
+    Email(EmailSettings),
+}
+
+impl NotifierKind {
+    /// `type` 값으로 빈 설정 생성.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "exe" => Ok(NotifierKind::Exe { path: None }),
+            "command" => Ok(NotifierKind::Command {
+                program: String::new(),
+                args: Vec::new(),
+            }),
+            "console" => Ok(NotifierKind::Console),
+            "teams" => Ok(NotifierKind::Teams {
+                url: String::new(),
+                card: CardFormat::default(),
+                retry: RetryPolicy::default(),
+                timeout: Duration::from_secs(DEFAULT_HTTP_TIMEOUT_SECS),
+            }),
+            "webhook" => Ok(NotifierKind::Webhook {
+                url: String::new(),
+                headers: Vec::new(),
+                body: None,
+                retry: RetryPolicy::default(),
+                timeout: Duration::from_secs(DEFAULT_HTTP_TIMEOUT_SECS),
+            }),
+            "email" => Ok(NotifierKind::Email(EmailSettings::default())),
+            other => Err(format!(
+                "알 수 없는 알림 종류입니다: '{}' (exe, command, console, teams, webhook, email)",
+                other
+            )),
+        }
+    }
+
+    /// 종류별 항목 적용. 알 수 없는 항목이면 Ok(false).
+    pub fn apply(&mut self, key: &str, value: &str) -> Result<bool, String> {
+        match (self, key) {
+            (NotifierKind::Exe { path }, "path") => *path = Some(PathBuf::from(value)),
+            (NotifierKind::Command { program, .. }, "command") => *program = value.to_string(),
+            (NotifierKind::Command { args, .. }, "arg" | "args") => args.push(value.to_string()),
+            (NotifierKind::Teams { url, .. }, "url") => *url = value.trim().to_string(),
+            (NotifierKind::Teams { card, .. }, "card") => *card = CardFormat::parse(value)?,
+            (NotifierKind::Teams { timeout, .. }, "timeout_seconds") => {
+                *timeout = parse_timeout(value)?
+            }
+            (NotifierKind::Teams { retry, .. }, key) => return retry.apply(key, value),
+            (NotifierKind::Webhook { url, .. }, "url") => *url = value.trim().to_string(),
+            (kind @ NotifierKind::Webhook { .. }, "header" | "headers") => {
+                let (name, header_value) = value
+                    .split_once(':')
+                    .ok_or_else(|| format!("'이름: 값' 형식이어야 합니다: '{}'", value))?;
+                return kind.add_header(name, header_value);
+            }
+            (NotifierKind::Webhook { body, .. }, "body") => *body = Some(BodyTemplate::new(value)?),
+            (NotifierKind::Webhook { timeout, .. }, "timeout_seconds") => {
+                *timeout = parse_timeout(value)?
+            }
+            (NotifierKind::Webhook { retry, .. }, key) => return retry.apply(key, value),
+            (NotifierKind::Email(settings), key) => return settings.apply(key, value),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// HTTP 헤더 하나 추가 (webhook). 다른 종류면 Ok(false).
+    pub fn add_header(&mut self, name: &str, value: &str) -> Result<bool, String> {
+        let NotifierKind::Webhook { headers, .. } = self else {
+            return Ok(false);
+        };
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(format!("헤더 이름이 비어 있습니다: ':{}'", value));
+        }
+        headers.push((name.to_string(), value.trim().to_string()));
+        Ok(true)
+    }
+
+    /// 필수 항목 확인.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            NotifierKind::Command { program, .. } if program.trim().is_empty() => {
+                Err("command 항목이 없습니다.".to_string())
+            }
+            NotifierKind::Teams { url, .. } | NotifierKind::Webhook { url, .. } => {
+                validate_url(url)
+            }
+            NotifierKind::Email(settings) => settings.validate(),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    value
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .filter(|s| *s > 0)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("1 이상의 초(정수)여야 합니다: '{}'", value))
+}
+
+fn validate_url(url: &str) -> Result<(), String> {
+    if url.is_empty() {
+        return Err("url 항목이 없습니다.".to_string());
+    }
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(format!(
+            "url은 http:// 또는 https://로 시작해야 합니다: '{}'",
+            url
+        ));
+    }
+    Ok(())
+}
+
+impl fmt::Display for NotifierKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotifierKind::Exe { path: Some(path) } => write!(f, "exe ({})", path.display()),
+            NotifierKind::Exe { path: None } => write!(f, "exe ({})", NOTIFICATION_EXE_NAME),
+            NotifierKind::Command { program, .. } => write!(f, "command ({})", program),
+            NotifierKind::Console => write!(f, "console"),
+            NotifierKind::Teams { card, retry, .. } => {
+                write!(f, "teams ({} 카드, 재시도 {}회)", card, retry.retries)
+            }
+            NotifierKind::Webhook { url, retry, .. } => {
+                write!(
+                    f,
+                    "webhook ({}, 재시도 {}회)",
+                    url_origin(url),
+                    retry.retries
+                )
+            }
+            NotifierKind::Email(settings) => {
+                write!(f, "email ({} -> {})", settings.host, settings.to.join(", "))?;
+                if let Some(at) = settings.digest_at {
+                    write!(f, ", 매일 {} 요약", at.format("%H:%M"))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// 이름을 붙인 알림 방법 설정.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotifierConfig {
+    pub name: String,
+    pub kind: NotifierKind,
+}
+
+/// 기본 제공 알림 방법. 같은 이름의 `[notifier:<이름>]` 섹션으로 바꿀 수 있습니다.
+pub fn builtin_notifiers() -> Vec<NotifierConfig> {
+    vec![
+        NotifierConfig {
+            name: TOAST_NOTIFIER.to_string(),
+            kind: NotifierKind::Exe { path: None },
+        },
+        NotifierConfig {
+            name: CONSOLE_NOTIFIER.to_string(),
+            kind: NotifierKind::Console,
+        },
+    ]
+}
+
+/// 알림 방법 하나로 보낸 결과.
+#[derive(Debug, Clone)]
+pub struct Delivery {
+    pub notifier: String,
+    pub title: String,
+    pub result: Result<(), String>,
+}
+
+/// 이름으로 알림 방법을 찾아 보내는 목록.
+pub struct NotifierRegistry {
+    notifiers: HashMap<String, Box<dyn Notifier>>,
+}
+
+impl NotifierRegistry {
+    /// 설정으로 알림 방법 생성. 상대 경로와 일일 요약 파일은 `exe_dir` 기준입니다.
+    pub fn from_configs(configs: &[NotifierConfig], exe_dir: &Path) -> Result<Self, String> {
+        let notifiers = configs
+            .iter()
+            .map(|config| {
+                let notifier: Box<dyn Notifier> = match &config.kind {
+                    NotifierKind::Exe { path } => {
+                        let path = match path {
+                            Some(path) => exe_dir.join(path),
+                            None => exe_dir.join(NOTIFICATION_EXE_NAME),
+                        };
+                        Box::new(ExeNotifier::new(path))
+                    }
+                    NotifierKind::Command { program, args } => {
+                        Box::new(CommandNotifier::new(program.clone(), args.clone()))
+                    }
+                    NotifierKind::Console => Box::new(ConsoleNotifier),
+                    NotifierKind::Teams {
+                        url,
+                        card,
+                        retry,
+                        timeout,
+                    } => Box::new(TeamsNotifier::new(url.clone(), *card, *retry, *timeout)),
+                    NotifierKind::Webhook {
+                        url,
+                        headers,
+                        body,
+                        retry,
+                        timeout,
+                    } => Box::new(WebhookNotifier::new(
+                        url.clone(),
+                        headers.clone(),
+                        body.clone(),
+                        *retry,
+                        *timeout,
+                    )),
+                    NotifierKind::Email(settings) => {
+                        let digest_path = exe_dir.join(format!(
+                            "{}{}.json",
+                            DIGEST_FILE_PREFIX,
+                            safe_file_name(&config.name)
+                        ));
+                        Box::new(
+                            EmailNotifier::new(settings, digest_path)
+                                .map_err(|e| format!("[notifier:{}] {}", config.name, e))?,
+                        )
+                    }
+                };
+                Ok((config.name.clone(), notifier))
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(NotifierRegistry { notifiers })
+    }
+
+    /// 정해진 시각에 보낼 알림 처리. 보낸 알림의 결과를 반환합니다.
+    pub fn poll(&self, now: DateTime<Local>) -> Vec<Delivery> {
+        self.notifiers
+            .iter()
+            .filter_map(|(name, notifier)| {
+                notifier.poll(now).map(|(title, result)| Delivery {
+                    notifier: name.clone(),
+                    title,
+                    result,
+                })
+            })
+            .collect()
+    }
+
+    /// 지정한 알림 방법들로 알림을 보내고 각각의 결과를 반환합니다.
+    pub fn send(&self, names: &[String], alert: &Alert) -> Vec<Delivery> {
+        names
+            .iter()
+            .map(|name| {
+                info!(
+                    "알림 실행 ({}): Title='{}', Message='{}'",
+                    name, alert.title, alert.message
+                );
+                let result = match self.notifiers.get(name) {
+                    Some(notifier) => notifier.notify(alert),
+                    None => Err(format!("등록되지 않은 알림 방법입니다: '{}'", name)),
+                };
+                Delivery {
+                    notifier: name.clone(),
+                    title: alert.title.clone(),
+                    result,
+                }
+            })
+            .collect()
+    }
+}
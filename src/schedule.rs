@@ -0,0 +1,327 @@
+// src/schedule.rs
+use std::fmt;
+
+use chrono::{
+    DateTime, Datelike, Duration as ChronoDuration, NaiveDate, NaiveDateTime, TimeZone, Timelike,
+};
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+// 윤년 2월 29일 + 요일 조합까지 찾을 수 있는 검색 범위
+const MAX_SEARCH_DAYS: u32 = 366 * 8;
+// 서머타임 시작으로 건너뛴 시각을 찾을 때 최대 탐색 범위(분)
+const MAX_GAP_MINUTES: i64 = 3 * 60;
+// 이 이상 시계가 뒤로 돌아가면 마지막 실행 기록을 버리고 현재 시각 기준으로 다시 계산
+const CLOCK_BACKWARD_TOLERANCE_SECS: i64 = 60 * 60;
+
+/// 누락 검사 실행 일정. `[schedule]` 섹션에서 설정합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Schedule {
+    /// 매시간 지정한 분에 실행 (`minutes = 11, 26, 41, 56`)
+    Minutes(Vec<u32>),
+    /// 자정 기준 N분 간격, offset분 지점에 실행 (`interval = 15`, `offset = 11`)
+    Interval { minutes: u32, offset: u32 },
+    /// 5필드 cron 표현식 (`cron = 11,26,41,56 9-18 * * 1-5`)
+    Cron(CronExpr),
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Schedule::Minutes(vec![11, 26, 41, 56])
+    }
+}
+
+impl Schedule {
+    /// `minutes` 값 파싱. 쉼표로 구분한 0~59 사이의 분 목록.
+    pub fn parse_minutes(value: &str) -> Result<Self, String> {
+        let minutes = value
+            .split(',')
+            .map(|m| {
+                let m = m.trim();
+                m.parse::<u32>()
+                    .map_err(|_| format!("0~59 사이의 분이어야 합니다: '{}'", m))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Schedule::minutes(minutes)
+    }
+
+    /// 0~59 사이의 분 목록으로 매시간 일정 생성. 중복된 분은 한 번만 실행합니다.
+    pub fn minutes(mut minutes: Vec<u32>) -> Result<Self, String> {
+        if minutes.is_empty() {
+            return Err("분 목록이 비어 있습니다.".to_string());
+        }
+        if let Some(m) = minutes.iter().find(|m| **m >= 60) {
+            return Err(format!("0~59 사이의 분이어야 합니다: '{}'", m));
+        }
+        minutes.sort_unstable();
+        minutes.dedup();
+        Ok(Schedule::Minutes(minutes))
+    }
+
+    /// `interval`(분)과 `offset`(분) 값으로 간격 일정 생성.
+    pub fn interval(minutes: u32, offset: u32) -> Result<Self, String> {
+        if minutes == 0 || minutes > 24 * 60 {
+            return Err(format!("간격은 1~1440분이어야 합니다: {}", minutes));
+        }
+        if offset >= minutes {
+            return Err(format!(
+                "offset({})은 간격({})보다 작아야 합니다.",
+                offset, minutes
+            ));
+        }
+        Ok(Schedule::Interval { minutes, offset })
+    }
+
+    /// 주어진 시각(분 단위)이 실행 시각인지 확인합니다.
+    pub fn matches(&self, at: NaiveDateTime) -> bool {
+        self.day_matches(at.date()) && self.time_matches(at.hour(), at.minute())
+    }
+
+    fn day_matches(&self, date: NaiveDate) -> bool {
+        match self {
+            Schedule::Minutes(_) | Schedule::Interval { .. } => true,
+            Schedule::Cron(expr) => expr.day_matches(date),
+        }
+    }
+
+    fn time_matches(&self, hour: u32, minute: u32) -> bool {
+        match self {
+            Schedule::Minutes(minutes) => minutes.contains(&minute),
+            Schedule::Interval { minutes, offset } => (hour * 60 + minute) % minutes == *offset,
+            Schedule::Cron(expr) => expr.time_matches(hour, minute),
+        }
+    }
+
+    /// `after` 이후(같은 분 제외) 첫 실행 시각(벽시계 기준).
+    ///
+    /// 날짜 단위로 건너뛰며 찾으므로 "2월 29일" 같은 드문 일정도 계산할 수 있습니다.
+    /// 검색 범위 안에 실행 시각이 없으면 None.
+    pub fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut date = after.date();
+        let mut from_minute = after.hour() * 60 + after.minute() + 1;
+
+        for _ in 0..MAX_SEARCH_DAYS {
+            if self.day_matches(date)
+                && let Some(minute_of_day) =
+                    (from_minute..MINUTES_PER_DAY).find(|m| self.time_matches(m / 60, m % 60))
+            {
+                return date.and_hms_opt(minute_of_day / 60, minute_of_day % 60, 0);
+            }
+            date = date.succ_opt()?;
+            from_minute = 0;
+        }
+        None
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Schedule::Minutes(minutes) => {
+                let list: Vec<String> = minutes.iter().map(|m| m.to_string()).collect();
+                write!(f, "매시간 {}분", list.join(", "))
+            }
+            Schedule::Interval { minutes, offset } => {
+                write!(f, "자정 기준 {}분 간격 (+{}분)", minutes, offset)
+            }
+            Schedule::Cron(expr) => write!(f, "cron '{}'", expr.source),
+        }
+    }
+}
+
+/// 분 시 일 월 요일 5필드 cron 표현식.
+///
+/// 각 필드는 `*`, `n`, `a-b`, `*/n`, `a-b/n` 과 쉼표 목록을 지원합니다.
+/// 요일은 0~7 (0과 7은 일요일). 일과 요일이 모두 지정되면 둘 중 하나만 맞아도 실행합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronExpr {
+    source: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    day_restricted: bool,
+    weekday_restricted: bool,
+}
+
+impl CronExpr {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = source.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(format!(
+                "cron 표현식은 '분 시 일 월 요일' 5개 필드여야 합니다: '{}'",
+                source
+            ));
+        }
+
+        let mut weekdays = parse_field(fields[4], 0, 7, "요일")?;
+        // 7(일요일)은 0으로 합침
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays & !(1 << 7)) | 1;
+        }
+
+        let days = parse_field(fields[2], 1, 31, "일")?;
+
+        // `*/1`, `0-6`, `1-31`처럼 모든 값을 포함하면 제한 없음으로 봄
+        Ok(CronExpr {
+            source: fields.join(" "),
+            minutes: parse_field(fields[0], 0, 59, "분")?,
+            hours: parse_field(fields[1], 0, 23, "시")?,
+            days,
+            months: parse_field(fields[3], 1, 12, "월")?,
+            weekdays,
+            day_restricted: days != full_set(1, 31),
+            weekday_restricted: weekdays != full_set(0, 6),
+        })
+    }
+
+    pub fn matches(&self, at: NaiveDateTime) -> bool {
+        self.day_matches(at.date()) && self.time_matches(at.hour(), at.minute())
+    }
+
+    fn day_matches(&self, date: NaiveDate) -> bool {
+        if !has_bit(self.months, date.month()) {
+            return false;
+        }
+
+        let day_match = has_bit(self.days, date.day());
+        let weekday_match = has_bit(self.weekdays, date.weekday().num_days_from_sunday());
+        match (self.day_restricted, self.weekday_restricted) {
+            (true, true) => day_match || weekday_match,
+            _ => day_match && weekday_match,
+        }
+    }
+
+    fn time_matches(&self, hour: u32, minute: u32) -> bool {
+        has_bit(self.hours, hour) && has_bit(self.minutes, minute)
+    }
+}
+
+fn has_bit(set: u64, value: u32) -> bool {
+    set & (1 << value) != 0
+}
+
+// min~max 값을 모두 포함하는 비트 집합
+fn full_set(min: u32, max: u32) -> u64 {
+    (min..=max).fold(0, |set, value| set | (1 << value))
+}
+
+// cron 필드 하나를 비트 집합으로 변환
+fn parse_field(field: &str, min: u32, max: u32, name: &str) -> Result<u64, String> {
+    let error = || format!("cron {} 필드 오류: '{}' ({}~{})", name, field, min, max);
+    let mut set = 0u64;
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (
+                range,
+                step.parse::<u32>()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .ok_or_else(error)?,
+            ),
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (
+                a.parse::<u32>().map_err(|_| error())?,
+                b.parse::<u32>().map_err(|_| error())?,
+            )
+        } else {
+            let value = range.parse::<u32>().map_err(|_| error())?;
+            // "5/15" 처럼 시작값만 있고 간격이 있으면 최대값까지 반복
+            (value, if part.contains('/') { max } else { value })
+        };
+
+        if start < min || end > max || start > end {
+            return Err(error());
+        }
+        for value in (start..=end).step_by(step as usize) {
+            set |= 1 << value;
+        }
+    }
+
+    Ok(set)
+}
+
+/// 다음 실행 예정. `slot`은 일정상의 벽시계 시각, `at`은 실제로 실행할 시각입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NextFire<Tz: TimeZone> {
+    pub slot: NaiveDateTime,
+    pub at: DateTime<Tz>,
+}
+
+/// 일정에서 다음 실행 시각을 계산하는 스케줄러.
+///
+/// 마지막으로 실행한 예정 시각을 기억해서 같은 예정 시각에 두 번 실행하지 않습니다.
+/// - 서머타임 종료로 같은 벽시계 시각이 반복되면 처음 한 번만 실행합니다.
+/// - 서머타임 시작으로 건너뛴 시각은 건너뛴 직후 첫 유효 시각에 실행합니다.
+/// - 시계가 앞으로 점프해 여러 예정 시각을 놓치면 한 번만 실행하고 다음 일정으로 넘어갑니다.
+/// - 시계가 1시간 이상 뒤로 돌아가면 현재 시각 기준으로 다시 계산합니다.
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    schedule: Schedule,
+    // (마지막 실행 예정 시각, 실제 실행 시각의 timestamp)
+    last_fired: Option<(NaiveDateTime, i64)>,
+}
+
+impl Scheduler {
+    pub fn new(schedule: Schedule) -> Self {
+        Scheduler {
+            schedule,
+            last_fired: None,
+        }
+    }
+
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// 일정만 바꿉니다. 마지막 실행 기록은 유지하므로 이미 실행한 예정 시각은 다시 실행하지 않습니다.
+    pub fn set_schedule(&mut self, schedule: Schedule) {
+        self.schedule = schedule;
+    }
+
+    /// `now` 기준 다음 실행 예정. 반환된 `at`이 `now` 이전이면 이미 실행할 때가 된 것입니다.
+    pub fn next_fire<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> Option<NextFire<Tz>> {
+        let current_minute = truncate_to_minute(now.naive_local());
+        let base = match self.last_fired {
+            Some((slot, fired_at))
+                if now.timestamp() >= fired_at - CLOCK_BACKWARD_TOLERANCE_SECS =>
+            {
+                slot
+            }
+            // 처음 실행하거나 시계가 크게 뒤로 돌아간 경우: 현재 분도 실행 대상에 포함
+            _ => current_minute - ChronoDuration::minutes(1),
+        };
+
+        let slot = self.schedule.next_after(base)?;
+        let at = resolve_local(&now.timezone(), slot)?;
+        Some(NextFire { slot, at })
+    }
+
+    /// 예정 시각 `slot`을 `now`에 실행했음을 기록합니다.
+    ///
+    /// `now`까지 지나간 다른 예정 시각도 함께 실행한 것으로 처리합니다.
+    pub fn mark_fired<Tz: TimeZone>(&mut self, slot: NaiveDateTime, now: &DateTime<Tz>) {
+        let current_minute = truncate_to_minute(now.naive_local());
+        self.last_fired = Some((slot.max(current_minute), now.timestamp()));
+    }
+}
+
+fn truncate_to_minute(at: NaiveDateTime) -> NaiveDateTime {
+    at.with_second(0)
+        .and_then(|t| t.with_nanosecond(0))
+        .unwrap_or(at)
+}
+
+// 벽시계 시각을 실제 시각으로 변환. 반복되는 시각은 처음 것을, 건너뛴 시각은 직후 첫 유효 시각을 사용
+fn resolve_local<Tz: TimeZone>(tz: &Tz, slot: NaiveDateTime) -> Option<DateTime<Tz>> {
+    (0..=MAX_GAP_MINUTES).find_map(|offset| {
+        tz.from_local_datetime(&(slot + ChronoDuration::minutes(offset)))
+            .earliest()
+    })
+}
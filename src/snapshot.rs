@@ -0,0 +1,176 @@
+// src/snapshot.rs
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use chrono::{DateTime, Duration as ChronoDuration, Local};
+use tracing::{debug, warn};
+
+use crate::{
+    consts::{DEFAULT_SNAPSHOT_BACKOFF_MS, DEFAULT_SNAPSHOT_RETRIES, DEFAULT_STALE_MINUTES},
+    source::{SheetSource, resolve_format, source_from_bytes},
+    utils::WorkbookConfig,
+};
+
+/// 원본 파일 읽기 재시도와 마지막 정상 스냅샷 설정. `[snapshot]` 섹션에서 설정합니다.
+#[derive(Debug, Clone)]
+pub struct SnapshotSettings {
+    /// 마지막 정상 스냅샷을 저장할 폴더. None이면 임시 폴더의 `checker-snapshots`
+    pub dir: Option<PathBuf>,
+    /// 첫 시도 이후 재시도 횟수
+    pub retries: u32,
+    /// 첫 재시도 전 대기 시간. 재시도마다 두 배로 늘어납니다.
+    pub backoff: Duration,
+    /// 스냅샷이 이 시간(분)보다 오래되면 데이터 지연으로 보고
+    pub stale_minutes: i64,
+}
+
+impl Default for SnapshotSettings {
+    fn default() -> Self {
+        SnapshotSettings {
+            dir: None,
+            retries: DEFAULT_SNAPSHOT_RETRIES,
+            backoff: Duration::from_millis(DEFAULT_SNAPSHOT_BACKOFF_MS),
+            stale_minutes: DEFAULT_STALE_MINUTES,
+        }
+    }
+}
+
+impl SnapshotSettings {
+    pub fn snapshot_dir(&self) -> PathBuf {
+        self.dir
+            .clone()
+            .unwrap_or_else(|| std::env::temp_dir().join("checker-snapshots"))
+    }
+}
+
+/// 원본을 읽었을 때 스냅샷을 새로 저장할지 여부.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotMode {
+    /// 원본을 읽으면 스냅샷 갱신 (서비스 실행)
+    Update,
+    /// 이전 스냅샷을 읽기만 하고 저장하지 않음 (`check-once`, `report`)
+    ReadOnly,
+}
+
+/// 원본 대신 마지막 정상 스냅샷을 사용한 경우의 정보.
+#[derive(Debug, Clone)]
+pub struct Fallback {
+    /// 스냅샷을 저장한 시각
+    pub taken_at: DateTime<Local>,
+    /// 검사 시각 기준 스냅샷의 나이
+    pub age: ChronoDuration,
+    /// 원본을 읽지 못한 이유
+    pub error: String,
+}
+
+/// 워크북 원본을 메모리로 읽어 검사 대상으로 엽니다.
+///
+/// 엑셀이 저장 중이거나 파일을 잠그고 있으면 간격을 두 배씩 늘려 가며 다시 시도하고,
+/// 성공하면 `SnapshotMode::Update`일 때 내용을 스냅샷 폴더에 저장합니다.
+/// 끝내 실패하면 마지막 정상 스냅샷을 열고 `Fallback`을 함께 반환합니다.
+pub fn open_with_snapshot(
+    workbook: &WorkbookConfig,
+    settings: &SnapshotSettings,
+    mode: SnapshotMode,
+    now: DateTime<Local>,
+) -> Result<(Box<dyn SheetSource>, Option<Fallback>), String> {
+    let format = resolve_format(&workbook.path, workbook.format);
+    let snapshot_path =
+        settings
+            .snapshot_dir()
+            .join(format!("{}.{}", safe_file_name(&workbook.name), format));
+
+    let mut delay = settings.backoff;
+    let mut last_error = String::new();
+    for attempt in 0..=settings.retries {
+        if attempt > 0 {
+            debug!(
+                "워크북 '{}' 다시 읽기 {}/{} ({}ms 후)",
+                workbook.name,
+                attempt,
+                settings.retries,
+                delay.as_millis()
+            );
+            thread::sleep(delay);
+            delay *= 2;
+        }
+
+        let result = fs::read(&workbook.path)
+            .map_err(|e| format!("파일 읽기 실패: {}", e))
+            .and_then(|data| {
+                let source = source_from_bytes(data.clone(), format)?;
+                Ok((source, data))
+            });
+        match result {
+            Ok((source, data)) => {
+                if mode == SnapshotMode::Update
+                    && let Err(e) = save_snapshot(&snapshot_path, &data, now)
+                {
+                    warn!(
+                        "워크북 '{}' 스냅샷 저장 실패: {}: {}",
+                        workbook.name,
+                        snapshot_path.display(),
+                        e
+                    );
+                }
+                return Ok((source, None));
+            }
+            Err(e) => {
+                warn!(
+                    "워크북 '{}' 읽기 실패 ({}/{}): {}",
+                    workbook.name,
+                    attempt + 1,
+                    settings.retries + 1,
+                    e
+                );
+                last_error = e;
+            }
+        }
+    }
+
+    // 재시도가 모두 실패하면 마지막 정상 스냅샷 사용
+    let taken_at = fs::metadata(&snapshot_path)
+        .and_then(|m| m.modified())
+        .map_err(|_| format!("{} (사용할 수 있는 이전 스냅샷 없음)", last_error))?;
+    let data = fs::read(&snapshot_path)
+        .map_err(|e| format!("{} (이전 스냅샷 읽기 실패: {})", last_error, e))?;
+    let source = source_from_bytes(data, format)
+        .map_err(|e| format!("{} (이전 스냅샷 열기 실패: {})", last_error, e))?;
+
+    let taken_at = DateTime::<Local>::from(taken_at);
+    Ok((
+        source,
+        Some(Fallback {
+            taken_at,
+            age: now - taken_at,
+            error: last_error,
+        }),
+    ))
+}
+
+// 스냅샷 저장. 수정 시각은 저장 시점(검사 시각)으로 맞춰 데이터 나이 계산에 사용
+fn save_snapshot(path: &Path, data: &[u8], now: DateTime<Local>) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, data)?;
+    fs::File::options()
+        .write(true)
+        .open(path)?
+        .set_modified(SystemTime::from(now))
+}
+
+/// 워크북/알림 이름을 파일 이름으로 쓸 수 있게 변환.
+pub fn safe_file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect()
+}
@@ -0,0 +1,154 @@
+// src/source.rs
+use std::{
+    fmt,
+    io::{Cursor, Read, Seek},
+    path::Path,
+};
+
+use calamine::{DataType, Ods, Range, Reader, Sheets, Xls, Xlsb, Xlsx, open_workbook_from_rs};
+
+/// 검사 대상 파일 형식. `[workbook:<이름>]`의 `format` 항목이나 파일 확장자로 정합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceFormat {
+    Xlsx,
+    Xls,
+    Xlsb,
+    Ods,
+    /// 파일 하나가 시트 하나인 CSV (UTF-8)
+    Csv,
+}
+
+impl SourceFormat {
+    /// `format` 설정 값 파싱. 대소문자 무시.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "xlsx" | "xlsm" => Ok(SourceFormat::Xlsx),
+            "xls" => Ok(SourceFormat::Xls),
+            "xlsb" => Ok(SourceFormat::Xlsb),
+            "ods" => Ok(SourceFormat::Ods),
+            "csv" => Ok(SourceFormat::Csv),
+            other => Err(format!(
+                "지원하지 않는 형식입니다: '{}' (xlsx, xls, xlsb, ods, csv)",
+                other
+            )),
+        }
+    }
+
+    /// 파일 확장자로 형식 판단.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?;
+        SourceFormat::parse(extension).ok()
+    }
+}
+
+impl fmt::Display for SourceFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SourceFormat::Xlsx => "xlsx",
+            SourceFormat::Xls => "xls",
+            SourceFormat::Xlsb => "xlsb",
+            SourceFormat::Ods => "ods",
+            SourceFormat::Csv => "csv",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 시트 이름으로 셀 범위를 읽을 수 있는 검사 대상.
+pub trait SheetSource {
+    fn sheet_range(&mut self, name: &str) -> Result<Range<DataType>, String>;
+}
+
+impl<RS: Read + Seek> SheetSource for Sheets<RS> {
+    fn sheet_range(&mut self, name: &str) -> Result<Range<DataType>, String> {
+        self.worksheet_range(name).map_err(|e| e.to_string())
+    }
+}
+
+/// CSV 파일 하나를 시트 하나로 다루는 검사 대상. 어떤 시트 이름으로 요청해도 같은 내용을 반환합니다.
+pub struct CsvSource {
+    range: Range<DataType>,
+}
+
+impl CsvSource {
+    pub fn from_reader(data: impl Read) -> Result<Self, String> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(data);
+
+        let mut rows = Vec::new();
+        for (row_idx, record) in reader.records().enumerate() {
+            let record = record.map_err(|e| format!("CSV {}행 읽기 실패: {}", row_idx + 1, e))?;
+            rows.push(record.iter().map(csv_cell).collect::<Vec<_>>());
+        }
+
+        let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        if rows.is_empty() || width == 0 {
+            return Ok(CsvSource {
+                range: Range::empty(),
+            });
+        }
+
+        let mut range = Range::new((0, 0), (rows.len() as u32 - 1, width as u32 - 1));
+        for (row_idx, row) in rows.into_iter().enumerate() {
+            for (col_idx, value) in row.into_iter().enumerate() {
+                range.set_value((row_idx as u32, col_idx as u32), value);
+            }
+        }
+        Ok(CsvSource { range })
+    }
+}
+
+impl SheetSource for CsvSource {
+    fn sheet_range(&mut self, _name: &str) -> Result<Range<DataType>, String> {
+        Ok(self.range.clone())
+    }
+}
+
+/// 파일 형식 결정. `format`이 None이면 확장자로 판단하고, 알 수 없으면 xlsx로 봅니다.
+pub fn resolve_format(path: &Path, format: Option<SourceFormat>) -> SourceFormat {
+    format
+        .or_else(|| SourceFormat::from_path(path))
+        .unwrap_or(SourceFormat::Xlsx)
+}
+
+/// 메모리에 읽어 둔 파일 내용으로 검사 대상을 만듭니다.
+pub fn source_from_bytes(
+    data: Vec<u8>,
+    format: SourceFormat,
+) -> Result<Box<dyn SheetSource>, String> {
+    let data = Cursor::new(data);
+    let source: Box<dyn SheetSource> = match format {
+        SourceFormat::Xlsx => Box::new(Sheets::Xlsx(
+            open_workbook_from_rs::<Xlsx<_>, _>(data).map_err(|e| e.to_string())?,
+        )),
+        SourceFormat::Xls => Box::new(Sheets::Xls(
+            open_workbook_from_rs::<Xls<_>, _>(data).map_err(|e| e.to_string())?,
+        )),
+        SourceFormat::Xlsb => Box::new(Sheets::Xlsb(
+            open_workbook_from_rs::<Xlsb<_>, _>(data).map_err(|e| e.to_string())?,
+        )),
+        SourceFormat::Ods => Box::new(Sheets::Ods(
+            open_workbook_from_rs::<Ods<_>, _>(data).map_err(|e| e.to_string())?,
+        )),
+        SourceFormat::Csv => Box::new(CsvSource::from_reader(data)?),
+    };
+    Ok(source)
+}
+
+// CSV 값은 문자열이므로, 숫자와 TRUE/FALSE는 엑셀 셀과 같은 타입으로 변환
+fn csv_cell(value: &str) -> DataType {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        DataType::Empty
+    } else if trimmed.eq_ignore_ascii_case("true") {
+        DataType::Bool(true)
+    } else if trimmed.eq_ignore_ascii_case("false") {
+        DataType::Bool(false)
+    } else if let Some(number) = trimmed.parse::<f64>().ok().filter(|n| n.is_finite()) {
+        DataType::Float(number)
+    } else {
+        DataType::String(value.to_string())
+    }
+}
@@ -0,0 +1,199 @@
+// src/teams.rs
+use std::{fmt, time::Duration};
+
+use serde_json::{Value, json};
+
+use crate::{
+    consts::MAX_CARD_ENTRIES,
+    notification::MissingEntry,
+    notifier::{Alert, Notifier, RetryPolicy, format_overdue},
+    webhook::post_json,
+};
+
+/// Teams로 보내는 카드 형식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CardFormat {
+    /// Adaptive Card (워크플로 웹후크)
+    #[default]
+    Adaptive,
+    /// MessageCard (기존 Incoming Webhook 커넥터)
+    Message,
+}
+
+impl CardFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "adaptive" => Ok(CardFormat::Adaptive),
+            "message" | "messagecard" => Ok(CardFormat::Message),
+            other => Err(format!(
+                "알 수 없는 카드 형식입니다: '{}' (adaptive, message)",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for CardFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardFormat::Adaptive => write!(f, "adaptive"),
+            CardFormat::Message => write!(f, "message"),
+        }
+    }
+}
+
+/// Teams Incoming Webhook으로 누락 목록 카드를 보내는 알림.
+///
+/// 2xx 응답이면 성공으로 보고, 연결 실패와 429/5xx 응답은 `retry` 설정대로 다시 시도합니다.
+pub struct TeamsNotifier {
+    url: String,
+    card: CardFormat,
+    retry: RetryPolicy,
+    agent: ureq::Agent,
+}
+
+impl TeamsNotifier {
+    pub fn new(url: String, card: CardFormat, retry: RetryPolicy, timeout: Duration) -> Self {
+        let agent = ureq::AgentBuilder::new().timeout(timeout).build();
+        TeamsNotifier {
+            url,
+            card,
+            retry,
+            agent,
+        }
+    }
+}
+
+impl Notifier for TeamsNotifier {
+    fn notify(&self, alert: &Alert) -> Result<(), String> {
+        let payload = match self.card {
+            CardFormat::Adaptive => adaptive_card(alert),
+            CardFormat::Message => message_card(alert),
+        };
+        let body = payload.to_string();
+        self.retry.run("Teams", || {
+            post_json(&self.agent, "Teams", &self.url, &[], &body)
+        })
+    }
+}
+
+// 누락 항목 한 줄의 (제목, 내용). 예: ("10-16 09:00 (12행)", "1시간 5분 지연 · 점검")
+fn entry_fact(entry: &MissingEntry) -> (String, String) {
+    let name = format!(
+        "{} ({}행)",
+        entry.scheduled.format("%m-%d %H:%M"),
+        entry.row
+    );
+    let mut value = format_overdue(entry.overdue);
+    if let Some(label) = &entry.label {
+        value.push_str(" · ");
+        value.push_str(label);
+    }
+    (name, value)
+}
+
+// 카드의 시트별 묶음
+struct CardSection {
+    heading: String,
+    facts: Vec<(String, String)>,
+}
+
+// 시트별 묶음. 최대 MAX_CARD_ENTRIES개까지 넣고 남은 개수를 함께 반환
+fn card_sections(alert: &Alert) -> (Vec<CardSection>, usize) {
+    let mut sections = Vec::new();
+    let mut shown = 0;
+    for ((workbook, sheet), entries) in alert.by_sheet() {
+        let room = MAX_CARD_ENTRIES - shown;
+        if room == 0 {
+            break;
+        }
+        let facts: Vec<(String, String)> =
+            entries.iter().take(room).map(|e| entry_fact(e)).collect();
+        shown += facts.len();
+        sections.push(CardSection {
+            heading: format!("{} / {} ({}건)", workbook, sheet, entries.len()),
+            facts,
+        });
+    }
+    (sections, alert.entries.len() - shown)
+}
+
+fn adaptive_card(alert: &Alert) -> Value {
+    let mut body = vec![
+        json!({
+            "type": "TextBlock",
+            "text": alert.title,
+            "size": "Medium",
+            "weight": "Bolder",
+            "wrap": true,
+        }),
+        json!({ "type": "TextBlock", "text": alert.message, "wrap": true }),
+    ];
+    let (sections, hidden) = card_sections(alert);
+    for section in sections {
+        body.push(json!({
+            "type": "TextBlock",
+            "text": section.heading,
+            "weight": "Bolder",
+            "spacing": "Medium",
+            "wrap": true,
+        }));
+        let facts: Vec<Value> = section
+            .facts
+            .into_iter()
+            .map(|(title, value)| json!({ "title": title, "value": value }))
+            .collect();
+        body.push(json!({ "type": "FactSet", "facts": facts }));
+    }
+    if hidden > 0 {
+        body.push(json!({
+            "type": "TextBlock",
+            "text": format!("외 {}건은 missing.txt를 확인하세요.", hidden),
+            "isSubtle": true,
+            "wrap": true,
+        }));
+    }
+    json!({
+        "type": "message",
+        "attachments": [{
+            "contentType": "application/vnd.microsoft.card.adaptive",
+            "contentUrl": null,
+            "content": {
+                "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                "type": "AdaptiveCard",
+                "version": "1.4",
+                "body": body,
+            },
+        }],
+    })
+}
+
+fn message_card(alert: &Alert) -> Value {
+    let (sections, hidden) = card_sections(alert);
+    let mut sections: Vec<Value> = sections
+        .into_iter()
+        .map(|section| {
+            let facts: Vec<Value> = section
+                .facts
+                .into_iter()
+                .map(|(name, value)| json!({ "name": name, "value": value }))
+                .collect();
+            json!({ "activityTitle": section.heading, "facts": facts })
+        })
+        .collect();
+    if hidden > 0 {
+        sections.push(json!({
+            "text": format!("외 {}건은 missing.txt를 확인하세요.", hidden),
+        }));
+    }
+    // MessageCard의 text는 마크다운이므로 줄바꿈을 문단으로 바꿈
+    json!({
+        "@type": "MessageCard",
+        "@context": "https://schema.org/extensions",
+        "summary": alert.title,
+        "themeColor": "D83B01",
+        "title": alert.title,
+        "text": alert.message.replace('\n', "\n\n"),
+        "sections": sections,
+    })
+}
@@ -0,0 +1,238 @@
+// src/template.rs
+use std::fmt;
+
+use chrono::{DateTime, Local};
+
+use crate::notifier::{AlertEntry, format_duration};
+
+// 어디서나 쓸 수 있는 자리표시자
+const GLOBAL_VARS: [&str; 8] = [
+    "count",
+    "new",
+    "total",
+    "sheets",
+    "sheet_count",
+    "now",
+    "earliest",
+    "max_overdue",
+];
+// {#entries} ... {/entries} 안에서만 쓸 수 있는 자리표시자
+const ENTRY_VARS: [&str; 6] = ["workbook", "sheet", "row", "scheduled", "overdue", "label"];
+
+const LOOP_START: &str = "#entries";
+const LOOP_END: &str = "/entries";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Text(String),
+    Var(String),
+    Entries(Vec<Node>),
+}
+
+/// 알림 제목/메시지 템플릿. 설정을 읽을 때 한 번 해석해 두고 알림마다 값을 채웁니다.
+///
+/// - `{count}` 이 알림의 누락 항목 수, `{new}` 그중 새 항목 수, `{total}` 전체 누락 항목 수
+/// - `{sheets}` 시트 이름 목록, `{sheet_count}` 시트 수, `{now}` 현재 시각
+/// - `{earliest}` 가장 이른 예정 일시, `{max_overdue}` 가장 긴 지연 시간
+/// - `{#entries}` ... `{/entries}` 항목마다 반복. 안에서 `{workbook}`, `{sheet}`, `{row}`,
+///   `{scheduled}`, `{overdue}`, `{label}` 사용
+/// - `{{`, `}}` 는 중괄호 문자 그대로
+///
+/// 반복 태그만 있는 줄은 줄바꿈 없이 태그만 사라집니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    source: String,
+    nodes: Vec<Node>,
+}
+
+/// 템플릿에 채울 값.
+pub struct TemplateContext<'a> {
+    pub count: usize,
+    pub new: usize,
+    pub total: usize,
+    pub now: DateTime<Local>,
+    pub entries: &'a [AlertEntry],
+}
+
+impl Template {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut stack: Vec<Vec<Node>> = vec![Vec::new()];
+        let mut text = String::new();
+        let mut rest = source;
+        while let Some(c) = rest.chars().next() {
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                text.push(c);
+                rest = &rest[2..];
+                continue;
+            }
+            if c == '}' {
+                return Err("'}' 앞에 '{'가 없습니다. 중괄호 문자는 '}}'로 적으세요.".to_string());
+            }
+            if c != '{' {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+
+            let end = rest.find('}').ok_or_else(|| {
+                "'{' 뒤에 '}'가 없습니다. 중괄호 문자는 '{{'로 적으세요.".to_string()
+            })?;
+            let name = rest[1..end].trim();
+            let at_line_start =
+                source.len() == rest.len() || source[..source.len() - rest.len()].ends_with('\n');
+            rest = &rest[end + 1..];
+            if !text.is_empty() {
+                let text = Node::Text(std::mem::take(&mut text));
+                stack
+                    .last_mut()
+                    .expect("템플릿 스택은 비지 않음")
+                    .push(text);
+            }
+            let node = Node::Var(name.to_string());
+
+            let in_loop = stack.len() > 1;
+            match name {
+                LOOP_START | LOOP_END => {
+                    if name == LOOP_START {
+                        if in_loop {
+                            return Err(
+                                "{#entries} 안에 다시 {#entries}를 쓸 수 없습니다.".to_string()
+                            );
+                        }
+                        stack.push(Vec::new());
+                    } else {
+                        if !in_loop {
+                            return Err("{/entries} 앞에 {#entries}가 없습니다.".to_string());
+                        }
+                        let body = stack.pop().expect("반복 안에서는 스택이 2단");
+                        stack
+                            .last_mut()
+                            .expect("템플릿 스택은 비지 않음")
+                            .push(Node::Entries(body));
+                    }
+                    // 태그만 있는 줄은 줄바꿈까지 제거
+                    if at_line_start && let Some(after) = rest.strip_prefix('\n') {
+                        rest = after;
+                    }
+                }
+                _ if GLOBAL_VARS.contains(&name) => stack
+                    .last_mut()
+                    .expect("템플릿 스택은 비지 않음")
+                    .push(node),
+                _ if ENTRY_VARS.contains(&name) => {
+                    if !in_loop {
+                        return Err(format!(
+                            "'{{{}}}'는 {{#entries}} ... {{/entries}} 안에서만 쓸 수 있습니다.",
+                            name
+                        ));
+                    }
+                    stack
+                        .last_mut()
+                        .expect("템플릿 스택은 비지 않음")
+                        .push(node);
+                }
+                _ => {
+                    return Err(format!(
+                        "알 수 없는 자리표시자입니다: '{{{}}}' (사용 가능: {}, {})",
+                        name,
+                        GLOBAL_VARS.join(", "),
+                        ENTRY_VARS.join(", ")
+                    ));
+                }
+            }
+        }
+        if stack.len() > 1 {
+            return Err("{#entries}가 {/entries}로 닫히지 않았습니다.".to_string());
+        }
+        let mut nodes = stack.pop().expect("템플릿 스택은 비지 않음");
+        if !text.is_empty() {
+            nodes.push(Node::Text(text));
+        }
+        Ok(Template {
+            source: source.to_string(),
+            nodes,
+        })
+    }
+
+    /// 값을 채운 문자열. 끝의 빈 줄은 지웁니다.
+    pub fn render(&self, context: &TemplateContext) -> String {
+        let mut output = String::new();
+        render_nodes(&self.nodes, context, None, &mut output);
+        output.trim_end().to_string()
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+fn render_nodes(
+    nodes: &[Node],
+    context: &TemplateContext,
+    entry: Option<&AlertEntry>,
+    output: &mut String,
+) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.push_str(text),
+            Node::Var(name) => output.push_str(&var_value(name, context, entry)),
+            Node::Entries(body) => {
+                for entry in context.entries {
+                    render_nodes(body, context, Some(entry), output);
+                }
+            }
+        }
+    }
+}
+
+fn var_value(name: &str, context: &TemplateContext, entry: Option<&AlertEntry>) -> String {
+    let entries = context.entries;
+    match (name, entry) {
+        ("workbook", Some(item)) => item.workbook.clone(),
+        ("sheet", Some(item)) => item.entry.sheet.clone(),
+        ("row", Some(item)) => item.entry.row.to_string(),
+        ("scheduled", Some(item)) => item.entry.scheduled.format("%Y-%m-%d %H:%M").to_string(),
+        ("overdue", Some(item)) => format_duration(item.entry.overdue),
+        ("label", Some(item)) => item.entry.label.clone().unwrap_or_default(),
+        ("count", _) => context.count.to_string(),
+        ("new", _) => context.new.to_string(),
+        ("total", _) => context.total.to_string(),
+        ("sheets", _) => sheet_names(entries).join(", "),
+        ("sheet_count", _) => sheet_names(entries).len().to_string(),
+        ("now", _) => context.now.format("%Y-%m-%d %H:%M").to_string(),
+        ("earliest", _) => entries
+            .iter()
+            .map(|item| item.entry.scheduled)
+            .min()
+            .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default(),
+        ("max_overdue", _) => entries
+            .iter()
+            .map(|item| item.entry.overdue)
+            .max()
+            .map(format_duration)
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+// 순서를 유지한 시트 이름 목록. 워크북이 여러 개면 "워크북/시트"
+fn sheet_names(entries: &[AlertEntry]) -> Vec<String> {
+    let multiple_workbooks = entries
+        .iter()
+        .any(|item| item.workbook != entries[0].workbook);
+    let mut names: Vec<String> = Vec::new();
+    for item in entries {
+        let name = if multiple_workbooks {
+            format!("{}/{}", item.workbook, item.entry.sheet)
+        } else {
+            item.entry.sheet.clone()
+        };
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
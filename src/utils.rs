@@ -1,136 +1,353 @@
 // src/utils.rs
 
+use chrono::{Duration as ChronoDuration, NaiveDate, NaiveDateTime, NaiveTime};
 use std::{
     collections::HashMap,
     error::Error,
     fs::{self, File},
-    io::{self, BufRead, BufReader, BufWriter, Write},
+    io::{self, BufWriter, Write},
     path::{Path, PathBuf},
-    sync::Once,
+    sync::{
+        Once, OnceLock,
+        atomic::{AtomicUsize, Ordering},
+    },
 };
-use tracing::{debug, error, info, warn};
+use tracing::{Event, Level, Subscriber, debug, info, warn};
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
-use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
+use tracing_subscriber::{
+    EnvFilter, Registry, fmt,
+    layer::{Context, Layer, SubscriberExt},
+    reload,
+    util::SubscriberInitExt,
+};
 
-use crate::consts::DATE_FORMAT;
+use crate::{
+    completion::CompletionRules,
+    escalation::EscalationPolicy,
+    formats::DateTimeFormats,
+    notification::{CheckResult, NotificationList},
+    notifier::NotifierConfig,
+    schedule::Schedule,
+    snapshot::SnapshotSettings,
+    source::SourceFormat,
+    template::Template,
+    watcher::WatchSettings,
+};
 
 #[derive(Debug)]
 pub struct Config {
-    pub excel_path: PathBuf,
-    pub manage_games: Vec<String>,
-    pub notification_title: Option<String>,
-    pub notification_message_template: Option<String>,
+    pub workbooks: Vec<WorkbookConfig>,
+    pub notification_title: Template,
+    pub notification_message_template: Template,
+    pub schedule: Schedule,
+    /// 예정 시각이 지난 뒤 누락으로 판단하기까지의 유예 시간(분)
+    pub grace_minutes: i64,
+    pub lookback: Lookback,
+    pub formats: DateTimeFormats,
+    pub completion: CompletionRules,
+    pub snapshot: SnapshotSettings,
+    pub watch: WatchSettings,
+    /// 같은 누락 항목을 다시 알리는 주기(분). 0이면 다시 알리지 않음
+    pub reminder_minutes: i64,
+    pub escalation: EscalationPolicy,
+    /// 사용할 수 있는 알림 방법 (기본 제공 + `[notifier:<이름>]`)
+    pub notifiers: Vec<NotifierConfig>,
 }
 
-// 실행 파일 위치 가져오기
-pub fn get_executable_dir() -> Result<PathBuf, Box<dyn Error>> {
-    let exe_path = std::env::current_exe()?;
-    Ok(exe_path
-        .parent()
-        .ok_or("실행 파일 경로를 찾을 수 없습니다.")?
-        .to_path_buf())
+/// 누락 검사 대상 기간. 예정 일시가 이 기간 안에 있는 행만 검사합니다. `[lookback]` 섹션에서 설정합니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Lookback {
+    /// 오늘 00:00부터 (`since = today`)
+    #[default]
+    Today,
+    /// 어제 00:00부터 (`since = yesterday`)
+    SinceYesterday,
+    /// 최근 N시간 (`hours = 6`)
+    Hours(u32),
 }
 
-// config.cfg 파일 읽기
-pub fn read_config(path: &Path) -> Result<Config, Box<dyn Error>> {
-    if !path.exists() {
-        error!("설정 파일({})을 찾을 수 없습니다.", path.display());
-        return Err(format!("설정 파일({})을 찾을 수 없습니다.", path.display()).into());
+impl Lookback {
+    /// `since` 값 파싱 (today, yesterday).
+    pub fn parse_since(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "today" => Ok(Lookback::Today),
+            "yesterday" => Ok(Lookback::SinceYesterday),
+            other => Err(format!("today 또는 yesterday 여야 합니다: '{}'", other)),
+        }
     }
 
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
+    /// 최근 `hours`시간. 1 이상이어야 합니다.
+    pub fn hours(hours: u32) -> Result<Self, String> {
+        if hours == 0 {
+            return Err("1 이상의 시간(정수)이어야 합니다: '0'".to_string());
+        }
+        Ok(Lookback::Hours(hours))
+    }
 
-    let mut excel_path_str = None;
-    let mut manage_games = Vec::new();
-    let mut notification_title = None;
-    let mut notification_message_template = None;
-    let mut current_section = "".to_string();
+    /// 검사 기간의 시작 시각.
+    pub fn window_start(&self, now: NaiveDateTime) -> NaiveDateTime {
+        let today_start = now.date().and_time(NaiveTime::MIN);
+        match self {
+            Lookback::Today => today_start,
+            Lookback::SinceYesterday => today_start - ChronoDuration::days(1),
+            Lookback::Hours(hours) => now - ChronoDuration::hours(*hours as i64),
+        }
+    }
+}
 
-    for line in reader.lines() {
-        let line = line?.trim().to_string();
-        if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
-            continue;
+impl std::fmt::Display for Lookback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Lookback::Today => write!(f, "오늘 00:00부터"),
+            Lookback::SinceYesterday => write!(f, "어제 00:00부터"),
+            Lookback::Hours(hours) => write!(f, "최근 {}시간", hours),
         }
+    }
+}
 
-        if line.starts_with('[') && line.ends_with(']') {
-            current_section = line[1..line.len() - 1].trim().to_lowercase();
-            continue;
+/// 시트 내 열 지정 방법. 열 문자("B", "AA") 또는 헤더 이름("날짜").
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnRef {
+    Index(usize),
+    Header(String),
+}
+
+impl ColumnRef {
+    /// 설정 값을 열 지정으로 변환합니다.
+    ///
+    /// 따옴표로 감싼 값은 항상 헤더 이름, 1~3자의 영문자는 열 문자, 그 외는 헤더 이름으로 취급합니다.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err("열 지정 값이 비어 있습니다.".to_string());
         }
 
-        match current_section.as_str() {
-            "target_path" => {
-                if excel_path_str.is_none() {
-                    excel_path_str = Some(line);
-                } else {
-                    warn!(
-                        "[target_path]에 여러 경로가 지정됨. 첫 번째 경로만 사용: {}",
-                        excel_path_str.as_ref().unwrap()
-                    );
-                }
-            }
-            "manage_game" => {
-                manage_games.push(line);
-            }
-            "title" => {
-                if notification_title.is_none() {
-                    notification_title = Some(line);
-                } else {
-                    warn!("[title]에 여러 줄이 지정됨. 첫 번째 줄만 사용합니다.");
-                }
+        if let Some(quoted) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+            let header = quoted.trim();
+            if header.is_empty() {
+                return Err("헤더 이름이 비어 있습니다.".to_string());
             }
-            "message" => {
-                if notification_message_template.is_none() {
-                    notification_message_template = Some(line);
-                } else {
-                    warn!("[message]에 여러 줄이 지정됨. 첫 번째 줄만 사용합니다.");
-                }
-            }
-            _ => {} // 다른 섹션 무시
+            return Ok(ColumnRef::Header(header.to_string()));
+        }
+
+        if value.len() <= 3 && value.chars().all(|c| c.is_ascii_alphabetic()) {
+            let index = value
+                .to_ascii_uppercase()
+                .bytes()
+                .fold(0usize, |acc, b| acc * 26 + (b - b'A' + 1) as usize);
+            return Ok(ColumnRef::Index(index - 1));
         }
+
+        Ok(ColumnRef::Header(value.to_string()))
     }
+}
+
+impl std::fmt::Display for ColumnRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnRef::Index(index) => write!(f, "{}열", column_letter(*index)),
+            ColumnRef::Header(name) => write!(f, "'{}' 헤더", name),
+        }
+    }
+}
+
+/// 시트별 열 매핑. 지정하지 않은 열은 헤더 행이 있으면 기본 헤더 이름("날짜", "시간", "완료")으로,
+/// 헤더 행이 없으면 B(날짜), C(시간), D(완료) 열로 찾습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnMapping {
+    pub date: Option<ColumnRef>,
+    pub time: Option<ColumnRef>,
+    /// 날짜와 시간이 한 열에 들어 있는 경우. 지정하면 date/time 대신 사용합니다.
+    pub datetime: Option<ColumnRef>,
+    pub done: Option<ColumnRef>,
+    pub description: Option<ColumnRef>,
+}
+
+/// 검사할 엑셀 파일 하나와 그 파일의 관리 대상 시트.
+///
+/// `[workbook:<이름>]` 섹션(`path`, `sheets`, `format`)으로 여러 개를 등록할 수 있고,
+/// 이전 형식의 `[target_path]` + `[manage_game]`은 파일 이름을 이름으로 하는 워크북 하나가 됩니다.
+/// CSV는 파일 하나가 시트 하나이므로 `sheets`를 생략하면 파일 이름을 시트 이름으로 사용합니다.
+#[derive(Debug, Clone)]
+pub struct WorkbookConfig {
+    pub name: String,
+    pub path: PathBuf,
+    /// 파일 형식 (`format = csv`). None이면 확장자로 판단합니다.
+    pub format: Option<SourceFormat>,
+    pub sheets: Vec<SheetConfig>,
+}
+
+/// 설정 파일의 워크북 하나(`[workbook:<이름>]`, `[[workbook]]`). 시트 설정과 합쳐 [`WorkbookConfig`]가 됩니다.
+pub struct WorkbookSection {
+    pub name: String,
+    pub path: Option<String>,
+    pub format: Option<SourceFormat>,
+    pub sheets: Vec<String>,
+}
+
+/// [manage_game]에 등록된 시트와 [sheet:<이름>] 섹션의 시트별 설정.
+#[derive(Debug, Clone)]
+pub struct SheetConfig {
+    pub name: String,
+    pub columns: ColumnMapping,
+    /// 헤더 행 번호 (1부터 시작). None이면 첫 행이 헤더인지 자동으로 판단합니다.
+    pub header_row: Option<usize>,
+    /// 시트별 유예 시간(분). None이면 [grace] 설정을 따릅니다.
+    pub grace_minutes: Option<i64>,
+}
 
-    let excel_path = match excel_path_str {
-        Some(p) => PathBuf::from(p),
-        None => {
-            error!("설정 파일에 [target_path] 섹션 또는 경로가 없습니다.");
-            return Err("설정 파일에 [target_path] 섹션 또는 경로가 없습니다.".into());
+impl SheetConfig {
+    pub fn new(name: String) -> Self {
+        SheetConfig {
+            name,
+            columns: ColumnMapping::default(),
+            header_row: None,
+            grace_minutes: None,
         }
-    };
+    }
+}
+
+// 0부터 시작하는 열 번호를 엑셀 열 문자로 변환 (0 -> A, 26 -> AA)
+pub fn column_letter(index: usize) -> String {
+    let mut n = index + 1;
+    let mut letters = Vec::new();
+    while n > 0 {
+        let rem = (n - 1) % 26;
+        letters.push((b'A' + rem as u8) as char);
+        n = (n - 1) / 26;
+    }
+    letters.iter().rev().collect()
+}
+
+// 실행 파일 위치 가져오기
+pub fn get_executable_dir() -> Result<PathBuf, Box<dyn Error>> {
+    let exe_path = std::env::current_exe()?;
+    Ok(exe_path
+        .parent()
+        .ok_or("실행 파일 경로를 찾을 수 없습니다.")?
+        .to_path_buf())
+}
 
-    if manage_games.is_empty() {
-        warn!("설정 파일에 [manage_game] 섹션 또는 관리할 게임 이름이 없습니다.");
-        return Err("설정 파일에 [manage_game] 섹션 또는 관리할 게임 이름이 없습니다.".into());
+/// 적용된 설정을 사람이 읽을 수 있는 줄 목록으로. 시작 로그와 `--check-config`가 사용합니다.
+///
+/// 생략한 항목은 기본값으로, `[sheet:<이름>]` 설정은 시트마다 풀어서 보여 줍니다.
+pub fn describe_config(config: &Config) -> Vec<String> {
+    let mut lines = Vec::new();
+    for workbook in &config.workbooks {
+        let format = workbook
+            .format
+            .map(|f| f.to_string())
+            .unwrap_or_else(|| "확장자로 판단".to_string());
+        lines.push(format!(
+            "워크북 '{}': {} (형식: {})",
+            workbook.name,
+            workbook.path.display(),
+            format
+        ));
+        for sheet in &workbook.sheets {
+            let columns = &sheet.columns;
+            let mut details: Vec<String> = [
+                ("날짜", &columns.date),
+                ("시간", &columns.time),
+                ("일시", &columns.datetime),
+                ("완료", &columns.done),
+                ("설명", &columns.description),
+            ]
+            .into_iter()
+            .filter_map(|(name, column)| column.as_ref().map(|c| format!("{} {}", name, c)))
+            .collect();
+            if details.is_empty() {
+                details.push("기본 열".to_string());
+            }
+            details.push(match sheet.header_row {
+                Some(row) => format!("헤더 {}행", row),
+                None => "헤더 자동".to_string(),
+            });
+            if let Some(grace) = sheet.grace_minutes {
+                details.push(format!("유예 {}분", grace));
+            }
+            lines.push(format!("  시트 '{}': {}", sheet.name, details.join(", ")));
+        }
+    }
+    lines.push(format!("알림 제목: {}", config.notification_title));
+    lines.push(format!(
+        "알림 메시지 템플릿: {:?}",
+        config.notification_message_template.to_string()
+    ));
+    lines.push(format!("실행 일정: {}", config.schedule));
+    lines.push(format!("유예 시간: {}분", config.grace_minutes));
+    lines.push(format!("검사 기간: {}", config.lookback));
+    lines.push(format!("문자열 날짜 형식: {:?}", config.formats.date));
+    lines.push(format!("문자열 시간 형식: {:?}", config.formats.time));
+    lines.push(format!("문자열 일시 형식: {:?}", config.formats.datetime));
+    let completion = &config.completion;
+    lines.push(format!("완료로 보는 값: {:?}", completion.done_values));
+    lines.push(format!("검사 제외 값: {:?}", completion.skip_values));
+    lines.push(format!("미완료로 보는 값: {:?}", completion.pending_values));
+    if let Some(pattern) = &completion.done_pattern {
+        lines.push(format!("완료 패턴: {}", pattern));
+    }
+    if let Some(pattern) = &completion.skip_pattern {
+        lines.push(format!("검사 제외 패턴: {}", pattern));
     }
+    if completion.any_value {
+        lines.push("제외/보류 값이 아닌 모든 값을 완료로 봄".to_string());
+    } else {
+        lines.push("완료 값이나 완료 패턴에 맞는 값만 완료로 봄".to_string());
+    }
+    for notifier in &config.notifiers {
+        lines.push(format!("알림 방법 '{}': {}", notifier.name, notifier.kind));
+    }
+    for tier in config.escalation.tiers() {
+        lines.push(format!(
+            "알림 단계 '{}': {}분 이상 지연, 채널 {}",
+            tier.name,
+            tier.after_minutes,
+            tier.channels.join(", ")
+        ));
+    }
+    lines.push(match config.reminder_minutes {
+        0 => "재알림: 하지 않음".to_string(),
+        minutes => format!("재알림: {}분마다", minutes),
+    });
+    lines.push(format!(
+        "스냅샷 폴더: {} (재시도 {}회, {}분 이상 지난 스냅샷은 데이터 지연으로 보고)",
+        config.snapshot.snapshot_dir().display(),
+        config.snapshot.retries,
+        config.snapshot.stale_minutes
+    ));
+    let watch = &config.watch;
+    lines.push(if watch.enabled {
+        format!(
+            "파일 변경 감지: {}초마다 확인, {}초 동안 변화 없으면 검사",
+            watch.poll_interval.as_secs(),
+            watch.debounce.as_secs()
+        )
+    } else {
+        "파일 변경 감지: 사용 안 함".to_string()
+    });
+    lines
+}
 
-    Ok(Config {
-        excel_path,
-        manage_games,
-        notification_title,
-        notification_message_template,
-    })
+// 엑셀 일련번호(1899-12-30 기준 일수, 소수부는 하루 중 시각)를 일시로 변환
+pub fn excel_serial_to_datetime(serial: f64) -> Option<NaiveDateTime> {
+    let excel_epoch = NaiveDate::from_ymd_opt(1899, 12, 30)?.and_time(NaiveTime::MIN);
+    let seconds = (serial * 86400.0).round() as i64;
+    excel_epoch.checked_add_signed(ChronoDuration::seconds(seconds))
 }
 
-pub fn excel_date_to_string(serial_date: f64) -> String {
-    use chrono::{Duration, NaiveDate};
-    let excel_epoch = NaiveDate::from_ymd_opt(1899, 12, 30).unwrap();
-    let days = serial_date.trunc() as i64;
-    let date = excel_epoch + Duration::days(days);
-    date.format(DATE_FORMAT).to_string()
+pub fn excel_serial_to_date(serial: f64) -> Option<NaiveDate> {
+    let excel_epoch = NaiveDate::from_ymd_opt(1899, 12, 30)?;
+    excel_epoch.checked_add_signed(ChronoDuration::days(serial.trunc() as i64))
 }
 
-pub fn excel_time_to_string(serial_time: f64) -> String {
-    let total_seconds = (serial_time * 86400.0).round() as u32;
-    let hours = total_seconds / 3600;
-    let minutes = (total_seconds % 3600) / 60;
-    let seconds = total_seconds % 60;
-    format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+// 일련번호의 소수부(하루 중 시각)만 사용
+pub fn excel_serial_to_time(serial: f64) -> Option<NaiveTime> {
+    let seconds = (serial.fract() * 86400.0).round() as u32 % 86400;
+    NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0)
 }
 
-pub fn write_missing_report(
-    path: &Path,
-    missing_data: &HashMap<String, Vec<String>>,
-) -> Result<(), Box<dyn Error>> {
+pub fn write_missing_report(path: &Path, result: &CheckResult) -> Result<(), Box<dyn Error>> {
     // 기존 파일 삭제 시도
     if path.exists() {
         match fs::remove_file(path) {
@@ -144,37 +361,124 @@ pub fn write_missing_report(
     let file = File::create(path)?;
     let mut writer = BufWriter::new(file);
 
-    if missing_data.is_empty() {
+    if result.missing_count() == 0 {
         info!("보고서 파일 작성: 누락된 항목이 없습니다.");
-        writeln!(writer, "누락된 알림 처리 항목이 없습니다.")?;
     } else {
         info!("누락된 항목 보고서 작성 시작...");
-        let mut sorted_sheets: Vec<&String> = missing_data.keys().collect();
-        sorted_sheets.sort();
-
-        for sheet_name in sorted_sheets {
-            if let Some(entries) = missing_data.get(sheet_name) {
-                writeln!(writer, "[{}]", sheet_name)?;
-                debug!(
-                    "시트 '{}'의 누락 항목 {}개 작성 중...",
-                    sheet_name,
-                    entries.len()
-                );
-                for entry in entries {
-                    writeln!(writer, "{}", entry)?;
-                }
-                writeln!(writer)?;
+    }
+    write_report(&mut writer, result)?;
+    if result.missing_count() > 0 {
+        info!("누락된 항목 보고서 작성 완료: {:?}", path);
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// missing.txt 형식으로 검사 결과 작성. `check-once`, `report` 명령은 표준 출력에 씁니다.
+pub fn write_report(writer: &mut impl Write, result: &CheckResult) -> io::Result<()> {
+    if result.missing_count() == 0 {
+        writeln!(writer, "누락된 알림 처리 항목이 없습니다.")?;
+    }
+
+    // 워크북이 여러 개면 워크북별로 구분해서 작성
+    let show_workbook = result.workbooks.len() > 1;
+    for workbook in &result.workbooks {
+        if show_workbook {
+            writeln!(
+                writer,
+                "===== {} ({}) =====",
+                workbook.name,
+                workbook.path.display()
+            )?;
+        }
+        if let Some(fallback) = &workbook.fallback {
+            let title = if workbook.stale {
+                "[데이터 지연]"
+            } else {
+                "[이전 스냅샷 사용]"
+            };
+            writeln!(writer, "{}", title)?;
+            writeln!(
+                writer,
+                "{} 스냅샷({}분 전)으로 검사했습니다. 원본 읽기 실패: {}",
+                fallback.taken_at.format("%Y-%m-%d %H:%M:%S"),
+                fallback.age.num_minutes(),
+                fallback.error
+            )?;
+            writeln!(writer)?;
+        }
+        if let Some(e) = &workbook.error {
+            writeln!(writer, "[워크북 열기 실패]")?;
+            writeln!(writer, "{}", e)?;
+            writeln!(writer)?;
+            continue;
+        }
+        write_workbook_report(writer, &workbook.missing, &workbook.sheet_errors)?;
+        if show_workbook && workbook.missing.is_empty() && workbook.sheet_errors.is_empty() {
+            writeln!(writer, "누락 없음")?;
+            writeln!(writer)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_workbook_report(
+    writer: &mut impl Write,
+    missing_data: &NotificationList,
+    sheet_errors: &HashMap<String, String>,
+) -> io::Result<()> {
+    let mut sorted_sheets: Vec<&String> = missing_data.keys().collect();
+    sorted_sheets.sort();
+
+    for sheet_name in sorted_sheets {
+        if let Some(entries) = missing_data.get(sheet_name) {
+            writeln!(writer, "[{}]", sheet_name)?;
+            debug!(
+                "시트 '{}'의 누락 항목 {}개 작성 중...",
+                sheet_name,
+                entries.len()
+            );
+            for entry in entries {
+                writeln!(writer, "{}", entry)?;
             }
+            writeln!(writer)?;
         }
-        info!("누락된 항목 보고서 작성 완료: {:?}", path);
     }
 
-    writer.flush()?;
+    if !sheet_errors.is_empty() {
+        let mut sorted_errors: Vec<(&String, &String)> = sheet_errors.iter().collect();
+        sorted_errors.sort();
+
+        writeln!(writer, "[검사 실패 시트]")?;
+        for (sheet_name, e) in sorted_errors {
+            writeln!(writer, "{}: {}", sheet_name, e)?;
+        }
+        writeln!(writer)?;
+    }
     Ok(())
 }
 
 static INIT: Once = Once::new();
 static mut GUARD: Option<tracing_appender::non_blocking::WorkerGuard> = None;
+static LOG_FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+static WARNING_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+// 출력된 경고 개수를 세는 레이어. `validate-config`가 결과 요약에 사용
+struct WarningCounter;
+
+impl<S: Subscriber> Layer<S> for WarningCounter {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if *event.metadata().level() == Level::WARN {
+            WARNING_COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// 로거 초기화 후 출력된 경고 로그 개수.
+pub fn warning_count() -> usize {
+    WARNING_COUNT.load(Ordering::Relaxed)
+}
+
 pub fn setup_logger() {
     INIT.call_once(|| {
         // 1. 파일 로거 설정
@@ -183,6 +487,9 @@ pub fn setup_logger() {
 
         // 2. 로그 레벨 필터 설정 (환경 변수 또는 기본값 INFO)
         let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")); // 기본 INFO 레벨
+        // 실행 중 콘솔의 `log <level>` 명령으로 교체할 수 있도록 reload 레이어로 감쌈
+        let (filter, filter_handle) = reload::Layer::new(filter);
+        let _ = LOG_FILTER_HANDLE.set(filter_handle);
 
         // 3. 콘솔 출력 레이어 설정
         let console_layer = fmt::layer()
@@ -210,6 +517,7 @@ pub fn setup_logger() {
             .with(filter) // 필터를 먼저 적용
             .with(console_layer) // 콘솔 레이어 추가
             .with(file_layer) // 파일 레이어 추가
+            .with(WarningCounter) // 경고 개수 집계
             .init(); // 전역 Subscriber로 설정
 
         unsafe {
@@ -219,3 +527,13 @@ pub fn setup_logger() {
         tracing::info!("로거 초기화 완료: 콘솔 및 파일(logs/app.log) 출력 활성화.");
     });
 }
+
+// 실행 중 로그 필터 변경 (EnvFilter 문법: "debug", "checker=trace" 등)
+pub fn set_log_level(directive: &str) -> Result<(), Box<dyn Error>> {
+    let handle = LOG_FILTER_HANDLE
+        .get()
+        .ok_or("로거가 초기화되지 않았습니다.")?;
+    let filter = EnvFilter::try_new(directive)?;
+    handle.reload(filter)?;
+    Ok(())
+}
@@ -0,0 +1,120 @@
+// src/watcher.rs
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use tokio::sync::mpsc::{self, Receiver, Sender};
+use tracing::{debug, info};
+
+use crate::consts::{DEFAULT_WATCH_DEBOUNCE_SECS, DEFAULT_WATCH_POLL_SECS};
+
+/// 검사 대상 파일 변경 감지 설정. `[watch]` 섹션에서 설정합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchSettings {
+    pub enabled: bool,
+    /// 파일 수정 시각/크기를 확인하는 간격
+    pub poll_interval: Duration,
+    /// 변경 후 이 시간 동안 더 바뀌지 않아야 저장이 끝난 것으로 봄
+    pub debounce: Duration,
+}
+
+impl Default for WatchSettings {
+    fn default() -> Self {
+        WatchSettings {
+            enabled: true,
+            poll_interval: Duration::from_secs(DEFAULT_WATCH_POLL_SECS),
+            debounce: Duration::from_secs(DEFAULT_WATCH_DEBOUNCE_SECS),
+        }
+    }
+}
+
+// 파일 변경 여부 판단에 쓰는 값. 파일이 없으면 None
+type FileSignature = Option<(SystemTime, u64)>;
+
+struct WatchedFile {
+    path: PathBuf,
+    stable: FileSignature,
+    // 아직 확정되지 않은 변경과 그 변경을 처음 본 시각
+    pending: Option<(FileSignature, Instant)>,
+}
+
+/// 파일 감시 스레드를 띄우고, 변경이 확정된 파일 경로를 받는 채널을 반환합니다.
+///
+/// 엑셀은 저장할 때 임시 파일을 만든 뒤 바꿔치기하므로 OS 알림 대신 수정 시각과 크기를 주기적으로 비교합니다.
+/// 네트워크 드라이브에서도 같은 방식으로 동작합니다.
+pub fn spawn_watcher(
+    paths: Vec<PathBuf>,
+    settings: &WatchSettings,
+) -> io::Result<Receiver<PathBuf>> {
+    let (tx, rx) = mpsc::channel(8);
+    let poll_interval = settings.poll_interval;
+    let debounce = settings.debounce;
+
+    let mut files: Vec<WatchedFile> = Vec::new();
+    for path in paths {
+        if files.iter().any(|f| f.path == path) {
+            continue;
+        }
+        files.push(WatchedFile {
+            stable: signature(&path),
+            path,
+            pending: None,
+        });
+    }
+
+    thread::Builder::new()
+        .name("watcher".to_string())
+        .spawn(move || run_watcher(files, poll_interval, debounce, tx))?;
+
+    Ok(rx)
+}
+
+fn run_watcher(
+    mut files: Vec<WatchedFile>,
+    poll_interval: Duration,
+    debounce: Duration,
+    tx: Sender<PathBuf>,
+) {
+    loop {
+        thread::sleep(poll_interval);
+
+        for file in &mut files {
+            let current = signature(&file.path);
+            if current == file.stable {
+                file.pending = None;
+                continue;
+            }
+
+            match &file.pending {
+                Some((pending, since)) if *pending == current => {
+                    if since.elapsed() < debounce {
+                        continue;
+                    }
+                    info!("파일 변경 감지: {}", file.path.display());
+                    file.stable = current;
+                    file.pending = None;
+                    if tx.blocking_send(file.path.clone()).is_err() {
+                        // 서비스 루프가 이미 종료됨
+                        return;
+                    }
+                }
+                _ => {
+                    debug!("파일 변경 중: {}", file.path.display());
+                    file.pending = Some((current, Instant::now()));
+                }
+            }
+        }
+
+        if tx.is_closed() {
+            return;
+        }
+    }
+}
+
+fn signature(path: &Path) -> FileSignature {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
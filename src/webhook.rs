@@ -0,0 +1,177 @@
+// src/webhook.rs
+use std::time::Duration;
+
+use serde_json::{Value, json};
+use tracing::info;
+
+use crate::notifier::{Alert, Notifier, RetryPolicy, SendError};
+
+// 본문 템플릿에서 사용할 수 있는 자리표시자. 모두 JSON 값으로 바뀝니다.
+const PLACEHOLDERS: [&str; 5] = ["title", "message", "count", "sheets", "entries"];
+
+/// 로그에 남길 URL의 `scheme://host[:port]` 부분. Slack, Discord 웹후크 URL은 경로에 토큰이 있으므로
+/// 경로, 쿼리, 사용자 정보는 표시하지 않습니다.
+pub fn url_origin(url: &str) -> String {
+    let (scheme, rest) = url.split_once("://").unwrap_or(("", url));
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    if scheme.is_empty() {
+        host.to_string()
+    } else {
+        format!("{}://{}", scheme, host)
+    }
+}
+
+/// JSON 본문을 POST하고 응답 코드를 로그에 남김. 네트워크 알림이 함께 사용합니다.
+///
+/// 2xx 응답이면 성공이고, 연결 실패와 429/5xx 응답은 다시 시도할 수 있는 실패로 봅니다.
+pub fn post_json(
+    agent: &ureq::Agent,
+    label: &str,
+    url: &str,
+    headers: &[(String, String)],
+    body: &str,
+) -> Result<(), SendError> {
+    let mut request = agent.post(url).set("Content-Type", "application/json");
+    for (name, value) in headers {
+        request = request.set(name, value);
+    }
+    match request.send_string(body) {
+        Ok(response) => {
+            info!("{} 응답: HTTP {}", label, response.status());
+            Ok(())
+        }
+        Err(ureq::Error::Status(code, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(SendError {
+                message: format!("HTTP {} 응답: {}", code, body.trim()),
+                retryable: code == 429 || code >= 500,
+            })
+        }
+        // Transport 오류의 Display에는 전체 URL이 들어가므로 종류와 내용만 사용
+        Err(ureq::Error::Transport(e)) => Err(SendError {
+            message: format!(
+                "연결 실패: {}: {}",
+                e.kind(),
+                e.message().unwrap_or_default()
+            ),
+            retryable: true,
+        }),
+    }
+}
+
+/// `[notifier:<이름>] body` 템플릿. `{{title}}`, `{{message}}`, `{{count}}`, `{{sheets}}`,
+/// `{{entries}}`를 JSON 값(문자열은 따옴표 포함)으로 바꿔 본문을 만듭니다.
+///
+/// 예: `body = {"text": {{message}}, "count": {{count}}, "items": {{entries}}}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BodyTemplate(String);
+
+impl BodyTemplate {
+    /// 템플릿 확인 후 생성. 예시 알림으로 만든 본문이 JSON이 아니면 오류.
+    pub fn new(template: &str) -> Result<Self, String> {
+        let template = BodyTemplate(template.trim().to_string());
+        serde_json::from_str::<Value>(&template.render(&Alert::default()))
+            .map_err(|e| format!("자리표시자를 바꾼 본문이 올바른 JSON이 아닙니다: {}", e))?;
+        Ok(template)
+    }
+
+    // 한 번에 훑으며 바꾸므로 값 안의 `{{...}}`는 다시 바뀌지 않음
+    pub fn render(&self, alert: &Alert) -> String {
+        let values = placeholder_values(alert);
+        let mut body = String::with_capacity(self.0.len());
+        let mut rest = self.0.as_str();
+        while let Some(start) = rest.find("{{") {
+            body.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let name = PLACEHOLDERS.iter().find(|name| {
+                rest[2..].starts_with(*name) && rest[2 + name.len()..].starts_with("}}")
+            });
+            match name {
+                Some(name) => {
+                    body.push_str(&values[*name].to_string());
+                    rest = &rest[name.len() + 4..];
+                }
+                None => {
+                    body.push_str("{{");
+                    rest = &rest[2..];
+                }
+            }
+        }
+        body.push_str(rest);
+        body
+    }
+}
+
+// 자리표시자 이름 -> 값
+fn placeholder_values(alert: &Alert) -> Value {
+    let mut sheets: Vec<&str> = Vec::new();
+    for item in &alert.entries {
+        if !sheets.contains(&item.entry.sheet.as_str()) {
+            sheets.push(&item.entry.sheet);
+        }
+    }
+    let entries: Vec<Value> = alert
+        .entries
+        .iter()
+        .map(|item| {
+            json!({
+                "workbook": item.workbook,
+                "sheet": item.entry.sheet,
+                "row": item.entry.row,
+                "scheduled": item.entry.scheduled.format("%Y-%m-%d %H:%M:%S").to_string(),
+                "overdue_minutes": item.entry.overdue.num_minutes(),
+                "label": item.entry.label,
+            })
+        })
+        .collect();
+    json!({
+        "title": alert.title,
+        "message": alert.message,
+        "count": alert.entries.len(),
+        "sheets": sheets,
+        "entries": entries,
+    })
+}
+
+/// 설정한 URL로 JSON 본문을 보내는 웹후크 알림 (Slack, Discord, 사내 시스템 등).
+///
+/// 본문 템플릿을 지정하지 않으면 모든 자리표시자 값을 담은 JSON 객체를 보냅니다.
+pub struct WebhookNotifier {
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<BodyTemplate>,
+    retry: RetryPolicy,
+    agent: ureq::Agent,
+}
+
+impl WebhookNotifier {
+    pub fn new(
+        url: String,
+        headers: Vec<(String, String)>,
+        body: Option<BodyTemplate>,
+        retry: RetryPolicy,
+        timeout: Duration,
+    ) -> Self {
+        let agent = ureq::AgentBuilder::new().timeout(timeout).build();
+        WebhookNotifier {
+            url,
+            headers,
+            body,
+            retry,
+            agent,
+        }
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn notify(&self, alert: &Alert) -> Result<(), String> {
+        let body = match &self.body {
+            Some(template) => template.render(alert),
+            None => placeholder_values(alert).to_string(),
+        };
+        self.retry.run("웹후크", || {
+            post_json(&self.agent, "웹후크", &self.url, &self.headers, &body)
+        })
+    }
+}
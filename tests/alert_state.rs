@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use checker::{
+    alert_state::{AlertState, AlertUpdate},
+    clock::{Clock, FixedClock},
+    escalation::EscalationPolicy,
+    notification::{CheckResult, MissingEntry, WorkbookResult},
+};
+use chrono::{Duration as ChronoDuration, Local, NaiveDate, NaiveDateTime, TimeZone};
+
+fn scheduled(hour: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 3, 10)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+}
+
+// `sheets` 시트를 검사했고 `missing` (시트, 예정 시각) 항목이 누락인 결과
+fn result(clock: &FixedClock, sheets: &[&str], missing: &[(&str, u32)]) -> CheckResult {
+    let now = clock.now().naive_local();
+    let mut list: HashMap<String, Vec<MissingEntry>> = HashMap::new();
+    for (sheet, hour) in missing {
+        list.entry(sheet.to_string())
+            .or_default()
+            .push(MissingEntry {
+                sheet: sheet.to_string(),
+                row: *hour as usize,
+                scheduled: scheduled(*hour),
+                overdue: now - scheduled(*hour),
+                label: None,
+            });
+    }
+    CheckResult {
+        workbooks: vec![WorkbookResult {
+            name: "live".to_string(),
+            sheets: sheets.iter().map(|s| s.to_string()).collect(),
+            missing: list,
+            ..Default::default()
+        }],
+        window_start: scheduled(0),
+    }
+}
+
+fn counts(update: &AlertUpdate) -> [usize; 4] {
+    [
+        update.new.len(),
+        update.reminders.len(),
+        update.escalated.len(),
+        update.resolved.len(),
+    ]
+}
+
+struct Tracker {
+    state: AlertState,
+    clock: FixedClock,
+    policy: EscalationPolicy,
+}
+
+impl Tracker {
+    fn new() -> Self {
+        Tracker {
+            state: AlertState::default(),
+            clock: FixedClock::new(Local.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap()),
+            policy: EscalationPolicy::new(Vec::new(), &["console".to_string()]).unwrap(),
+        }
+    }
+
+    fn update(&mut self, result: &CheckResult) -> AlertUpdate {
+        let reminder = Some(ChronoDuration::minutes(60));
+        self.state
+            .update(result, self.clock.now(), reminder, &self.policy)
+    }
+}
+
+#[test]
+fn new_entries_notify_once() {
+    let mut t = Tracker::new();
+    let update = t.update(&result(&t.clock, &["일정"], &[("일정", 9)]));
+    assert_eq!(counts(&update), [1, 0, 0, 0]);
+    assert!(update.should_notify());
+
+    t.clock.advance(ChronoDuration::minutes(15));
+    let update = t.update(&result(&t.clock, &["일정"], &[("일정", 9)]));
+    assert_eq!(counts(&update), [0, 0, 0, 0]);
+    assert!(!update.should_notify());
+    assert_eq!(t.state.open_count(), 1);
+}
+
+#[test]
+fn open_entries_are_reminded_after_the_cadence() {
+    let mut t = Tracker::new();
+    t.update(&result(&t.clock, &["일정"], &[("일정", 9)]));
+
+    t.clock.advance(ChronoDuration::minutes(59));
+    let update = t.update(&result(&t.clock, &["일정"], &[("일정", 9)]));
+    assert_eq!(counts(&update), [0, 0, 0, 0]);
+
+    t.clock.advance(ChronoDuration::minutes(1));
+    let update = t.update(&result(&t.clock, &["일정"], &[("일정", 9), ("일정", 10)]));
+    assert_eq!(counts(&update), [1, 1, 0, 0]);
+    assert_eq!(update.reminders[0].scheduled, scheduled(9));
+
+    // 재알림 주기는 마지막 알림부터 다시 셈
+    t.clock.advance(ChronoDuration::minutes(30));
+    let update = t.update(&result(&t.clock, &["일정"], &[("일정", 9), ("일정", 10)]));
+    assert_eq!(counts(&update), [0, 0, 0, 0]);
+}
+
+#[test]
+fn completed_entries_are_resolved() {
+    let mut t = Tracker::new();
+    t.update(&result(&t.clock, &["일정"], &[("일정", 9), ("일정", 10)]));
+
+    t.clock.advance(ChronoDuration::minutes(5));
+    let update = t.update(&result(&t.clock, &["일정"], &[("일정", 10)]));
+    assert_eq!(counts(&update), [0, 0, 0, 1]);
+    assert_eq!(update.resolved[0].scheduled, scheduled(9));
+    assert!(update.should_notify());
+    assert_eq!(t.state.open_count(), 1);
+}
+
+#[test]
+fn unchecked_sheets_keep_their_alerts() {
+    let mut t = Tracker::new();
+    t.update(&result(&t.clock, &["일정"], &[("일정", 9)]));
+
+    let mut failed = result(&t.clock, &["일정"], &[]);
+    failed.workbooks[0]
+        .sheet_errors
+        .insert("일정".to_string(), "시트를 찾을 수 없습니다.".to_string());
+    let update = t.update(&failed);
+    assert_eq!(counts(&update), [0, 0, 0, 0]);
+    assert_eq!(t.state.open_count(), 1);
+}
+
+#[test]
+fn removed_sheets_are_purged_without_resolving() {
+    let mut t = Tracker::new();
+    t.update(&result(
+        &t.clock,
+        &["일정", "점검"],
+        &[("일정", 9), ("점검", 10)],
+    ));
+
+    // 설정에서 '점검' 시트를 뺌
+    let update = t.update(&result(&t.clock, &["일정"], &[("일정", 9)]));
+    assert_eq!(counts(&update), [0, 0, 0, 0]);
+    assert_eq!(t.state.open_count(), 1);
+    assert_eq!(t.state.records()[0].sheet, "일정");
+}
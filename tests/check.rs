@@ -0,0 +1,140 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use checker::{
+    clock::FixedClock,
+    config_file::read_config,
+    notification::{CheckResult, check_for_missed_notifications},
+    snapshot::SnapshotMode,
+};
+use chrono::{DateTime, Duration as ChronoDuration, Local, TimeZone};
+
+static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+// 테스트마다 다른 임시 폴더
+fn temp_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "checker-check-test-{}-{}",
+        process::id(),
+        NEXT_DIR.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// CSV 시트 하나(`일정`)를 검사하는 설정을 만들고 검사
+fn check(csv: &str, clock: &FixedClock) -> CheckResult {
+    let dir = temp_dir();
+    let csv_path = dir.join("일정.csv");
+    fs::write(&csv_path, csv).unwrap();
+    let config_path = dir.join("config.toml");
+    fs::write(
+        &config_path,
+        format!(
+            r#"
+[grace]
+minutes = 9
+
+[snapshot]
+dir = '{}'
+
+[[workbook]]
+name = "csv"
+path = '{}'
+"#,
+            dir.join("snap").display(),
+            csv_path.display()
+        ),
+    )
+    .unwrap();
+    let config = read_config(&config_path).unwrap();
+    let result = check_for_missed_notifications(&config, clock, SnapshotMode::ReadOnly).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    result
+}
+
+fn missing_rows(result: &CheckResult) -> Vec<usize> {
+    let workbook = &result.workbooks[0];
+    assert_eq!(workbook.error, None);
+    assert!(
+        workbook.sheet_errors.is_empty(),
+        "{:?}",
+        workbook.sheet_errors
+    );
+    let mut rows: Vec<usize> = workbook
+        .missing
+        .get("일정")
+        .map_or_else(Vec::new, |entries| entries.iter().map(|e| e.row).collect());
+    rows.sort();
+    rows
+}
+
+fn at(hour: u32, minute: u32, second: u32) -> DateTime<Local> {
+    Local
+        .with_ymd_and_hms(2026, 3, 10, hour, minute, second)
+        .unwrap()
+}
+
+#[test]
+fn reports_past_open_rows_only() {
+    let csv = "\
+번호,날짜,시간,완료
+1,2026-03-10,09:00,
+2,2026-03-10,10:00,완료
+3,2026-03-10,11:00,취소
+4,2026-03-10,13:00,
+5,2026-03-09,10:00,
+";
+    let clock = FixedClock::new(at(12, 0, 0));
+    assert_eq!(missing_rows(&check(csv, &clock)), [2]);
+
+    // 예정 시각이 지나고 유예 시간도 지나면 누락
+    clock.advance(ChronoDuration::hours(2));
+    assert_eq!(missing_rows(&check(csv, &clock)), [2, 5]);
+}
+
+#[test]
+fn grace_period_ends_at_exactly_grace_minutes() {
+    let csv = "번호,날짜,시간,완료\n1,2026-03-10,11:51,\n";
+    let clock = FixedClock::new(at(11, 59, 59));
+    assert!(missing_rows(&check(csv, &clock)).is_empty());
+
+    clock.set(at(12, 0, 0));
+    let result = check(csv, &clock);
+    assert_eq!(missing_rows(&result), [2]);
+    let entry = &result.workbooks[0].missing["일정"][0];
+    assert_eq!(entry.overdue, ChronoDuration::minutes(9));
+}
+
+#[test]
+fn missing_done_header_is_a_sheet_error() {
+    let csv = "\
+No,날짜,시간,처리
+1,2026-03-10,09:00,완료
+2,2026-03-10,10:00,
+";
+    let clock = FixedClock::new(at(12, 0, 0));
+    let result = check(csv, &clock);
+    let workbook = &result.workbooks[0];
+    assert!(workbook.missing.is_empty());
+    let error = &workbook.sheet_errors["일정"];
+    assert!(
+        error.contains("완료 열('완료'/'완료 여부'/'done')"),
+        "{error}"
+    );
+}
+
+#[test]
+fn sheet_without_header_row_uses_columns_b_c_d() {
+    let csv = "\
+1,2026-03-10,09:00,
+2,2026-03-10,10:00,완료
+";
+    let clock = FixedClock::new(at(12, 0, 0));
+    assert_eq!(missing_rows(&check(csv, &clock)), [1]);
+}
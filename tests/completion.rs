@@ -0,0 +1,70 @@
+use calamine::DataType;
+use checker::completion::{CompletionRules, RowStatus};
+
+fn text(value: &str) -> DataType {
+    DataType::String(value.to_string())
+}
+
+#[test]
+fn default_rules_classify_cells() {
+    let rules = CompletionRules::default();
+    let cases = [
+        (None, RowStatus::Open),
+        (Some(DataType::Empty), RowStatus::Open),
+        (Some(text("  ")), RowStatus::Open),
+        (Some(text("완료")), RowStatus::Done),
+        (Some(text(" Done ")), RowStatus::Done),
+        // 목록에 없는 값도 완료 (any_value 기본값)
+        (Some(text("김철수")), RowStatus::Done),
+        (Some(text("보류")), RowStatus::Open),
+        (Some(text("PENDING")), RowStatus::Open),
+        (Some(text("x")), RowStatus::Open),
+        (Some(text("취소")), RowStatus::Skipped),
+        (Some(text(" Skipped")), RowStatus::Skipped),
+        (Some(DataType::Bool(true)), RowStatus::Done),
+        (Some(DataType::Bool(false)), RowStatus::Open),
+        (Some(DataType::Int(0)), RowStatus::Open),
+        (Some(DataType::Float(1.0)), RowStatus::Done),
+        (Some(DataType::DateTime(45766.5)), RowStatus::Done),
+    ];
+    for (cell, expected) in cases {
+        assert_eq!(rules.classify(cell.as_ref()), expected, "{cell:?}");
+    }
+}
+
+#[test]
+fn configured_values_replace_the_defaults() {
+    let mut rules = CompletionRules::default();
+    assert_eq!(rules.apply("pending", "검토중, 확인 필요"), Ok(true));
+    assert_eq!(rules.apply("skip", "없음"), Ok(true));
+
+    assert_eq!(rules.classify(Some(&text("확인 필요"))), RowStatus::Open);
+    assert_eq!(rules.classify(Some(&text("없음"))), RowStatus::Skipped);
+    // 기본 목록은 더 이상 사용하지 않음
+    assert_eq!(rules.classify(Some(&text("보류"))), RowStatus::Done);
+    assert_eq!(rules.classify(Some(&text("취소"))), RowStatus::Done);
+}
+
+#[test]
+fn any_value_false_requires_done_values() {
+    let mut rules = CompletionRules::default();
+    assert_eq!(rules.apply("any_value", "false"), Ok(true));
+    assert_eq!(rules.apply("done_pattern", "^완료"), Ok(true));
+    assert_eq!(rules.apply("skip_pattern", "^취소"), Ok(true));
+
+    assert_eq!(rules.classify(Some(&text("Y"))), RowStatus::Done);
+    assert_eq!(rules.classify(Some(&text("완료(오후)"))), RowStatus::Done);
+    assert_eq!(rules.classify(Some(&text("김철수"))), RowStatus::Open);
+    assert_eq!(rules.classify(Some(&text("취소됨"))), RowStatus::Skipped);
+    // 제외가 완료보다 먼저
+    assert_eq!(rules.apply("done", "취소됨"), Ok(true));
+    assert_eq!(rules.classify(Some(&text("취소됨"))), RowStatus::Skipped);
+}
+
+#[test]
+fn rejects_invalid_settings() {
+    let mut rules = CompletionRules::default();
+    assert_eq!(rules.apply("unknown", "x"), Ok(false));
+    assert!(rules.apply("any_value", "maybe").is_err());
+    assert!(rules.apply("done_pattern", "(").is_err());
+}
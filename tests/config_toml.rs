@@ -0,0 +1,132 @@
+use checker::{config_file::parse_toml, notifier::NotifierKind};
+
+const WORKBOOK: &str = r#"
+[[workbook]]
+name = "live"
+path = "live.xlsx"
+sheets = "t"
+"#;
+
+#[test]
+fn list_items_keep_commas() {
+    let source = r#"
+[completion]
+done = ["ok, 확인"]
+
+[[workbook]]
+name = "live"
+path = "live.xlsx"
+sheets = ["Q1, Q2", "요약"]
+"#;
+    let config = parse_toml(source).unwrap().build().unwrap();
+
+    let sheets: Vec<&str> = config.workbooks[0]
+        .sheets
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(sheets, ["Q1, Q2", "요약"]);
+    assert_eq!(config.completion.done_values, ["ok, 확인"]);
+}
+
+#[test]
+fn multiline_templates_are_kept_as_written() {
+    let source = format!(
+        r#"
+title = """첫 줄
+둘째 줄"""
+
+[notify]
+channels = "console"
+
+[[tier]]
+name = "긴급"
+after_minutes = 30
+message = """
+  {{count}}건 누락
+    - 들여쓰기"""
+{WORKBOOK}"#
+    );
+    let config = parse_toml(&source).unwrap().build().unwrap();
+
+    assert_eq!(config.notification_title.to_string(), "첫 줄\n둘째 줄");
+    let tier = &config.escalation.tiers()[0];
+    assert_eq!(
+        tier.message.as_ref().unwrap().to_string(),
+        "  {count}건 누락\n    - 들여쓰기"
+    );
+}
+
+#[test]
+fn value_errors_report_the_line() {
+    let source = format!("{WORKBOOK}\n[sheet.t]\nheader_row = 0\n");
+    let error = parse_toml(&source).err().unwrap().to_string();
+    assert!(
+        error.starts_with("설정 파일 8행: [sheet.t] 'header_row'"),
+        "{error}"
+    );
+
+    let error = parse_toml("[schedule]\nminute = [5]\n")
+        .err()
+        .unwrap()
+        .to_string();
+    assert!(error.starts_with("설정 파일 2행 1열:"), "{error}");
+}
+
+#[test]
+fn notifier_list_keys_accept_both_spellings() {
+    let kind = |source: &str| {
+        let config = parse_toml(&format!("{source}{WORKBOOK}"))
+            .unwrap()
+            .build()
+            .unwrap();
+        config.notifiers.last().unwrap().kind.clone()
+    };
+    let command = kind(
+        r#"
+[notifier.run]
+type = "command"
+command = "notify"
+args = ["-t", "{title}"]
+"#,
+    );
+    let singular = kind(
+        r#"
+[notifier.run]
+type = "command"
+command = "notify"
+arg = ["-t", "{title}"]
+"#,
+    );
+    assert_eq!(command, singular);
+
+    let webhook = kind(
+        r#"
+[notifier.hook]
+type = "webhook"
+url = "http://127.0.0.1/hook"
+headers = { Authorization = "Bearer x" }
+"#,
+    );
+    let singular = kind(
+        r#"
+[notifier.hook]
+type = "webhook"
+url = "http://127.0.0.1/hook"
+header = { Authorization = "Bearer x" }
+"#,
+    );
+    assert_eq!(webhook, singular);
+
+    // INI 형식은 한 줄에 값 하나
+    let mut ini = NotifierKind::parse("command").unwrap();
+    ini.apply("command", "notify").unwrap();
+    assert_eq!(ini.apply("arg", "-t"), Ok(true));
+    assert_eq!(ini.apply("args", "{title}"), Ok(true));
+    assert_eq!(ini, command);
+
+    let mut ini = NotifierKind::parse("webhook").unwrap();
+    ini.apply("url", "http://127.0.0.1/hook").unwrap();
+    assert_eq!(ini.apply("headers", "Authorization: Bearer x"), Ok(true));
+    assert_eq!(ini, webhook);
+}
@@ -0,0 +1,251 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+    process,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use checker::{
+    email::{EmailNotifier, EmailSettings, SmtpSecurity},
+    notification::MissingEntry,
+    notifier::{Alert, AlertEntry, Notifier, RetryPolicy},
+};
+use chrono::{DateTime, Duration as ChronoDuration, Local, NaiveDate, NaiveTime, TimeZone};
+
+// 받은 메일(DATA 내용)을 모으는 SMTP 서버. 포트와 메일 목록을 반환
+fn serve() -> (u16, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let mails = Arc::new(Mutex::new(Vec::new()));
+    let received = Arc::clone(&mails);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut reader = BufReader::new(stream.unwrap());
+            let reply = |reader: &mut BufReader<TcpStream>, text: &str| {
+                write!(reader.get_mut(), "{}\r\n", text).unwrap();
+            };
+            reply(&mut reader, "220 localhost");
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 {
+                    break;
+                }
+                let command = line.trim_end().to_uppercase();
+                if command.starts_with("DATA") {
+                    reply(&mut reader, "354 send");
+                    let mut data = String::new();
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if line == ".\r\n" {
+                            break;
+                        }
+                        data.push_str(&line);
+                    }
+                    received.lock().unwrap().push(data);
+                    reply(&mut reader, "250 queued");
+                } else if command.starts_with("QUIT") {
+                    reply(&mut reader, "221 bye");
+                    break;
+                } else {
+                    reply(&mut reader, "250 ok");
+                }
+            }
+        }
+    });
+    (port, mails)
+}
+
+// 메일의 (Content-Type, 디코딩한 본문) 목록. base64와 quoted-printable만 처리
+fn parts(mail: &str) -> Vec<(String, String)> {
+    let boundary = mail
+        .split("boundary=\"")
+        .nth(1)
+        .and_then(|rest| rest.split('"').next())
+        .unwrap();
+    mail.split(&format!("--{}", boundary))
+        .skip(1)
+        .take_while(|part| !part.starts_with("--"))
+        .filter_map(|part| {
+            let (headers, body) = part.split_once("\r\n\r\n")?;
+            let header = |name: &str| {
+                headers
+                    .lines()
+                    .find_map(|line| line.strip_prefix(name))
+                    .map(str::trim)
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let bytes = match header("Content-Transfer-Encoding:").as_str() {
+                "base64" => decode_base64(body),
+                "quoted-printable" => decode_quoted_printable(body),
+                _ => body.as_bytes().to_vec(),
+            };
+            Some((
+                header("Content-Type:"),
+                String::from_utf8(bytes).unwrap().replace("\r\n", "\n"),
+            ))
+        })
+        .collect()
+}
+
+fn decode_base64(text: &str) -> Vec<u8> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut bytes = Vec::new();
+    let (mut buffer, mut bits) = (0u32, 0);
+    for c in text.bytes() {
+        let Some(value) = ALPHABET.iter().position(|a| *a == c) else {
+            continue;
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    bytes
+}
+
+fn decode_quoted_printable(text: &str) -> Vec<u8> {
+    let text = text.replace("=\r\n", "");
+    let mut bytes = Vec::new();
+    let mut rest = text.as_bytes();
+    while let Some((&c, tail)) = rest.split_first() {
+        if c == b'=' && tail.len() >= 2 {
+            let hex = std::str::from_utf8(&tail[..2]).unwrap();
+            bytes.push(u8::from_str_radix(hex, 16).unwrap());
+            rest = &tail[2..];
+        } else {
+            bytes.push(c);
+            rest = tail;
+        }
+    }
+    bytes
+}
+
+fn settings(port: u16) -> EmailSettings {
+    EmailSettings {
+        host: "127.0.0.1".to_string(),
+        port: Some(port),
+        security: SmtpSecurity::None,
+        from: "checker@example.com".to_string(),
+        to: vec!["ops@example.com".to_string()],
+        retry: RetryPolicy {
+            retries: 0,
+            backoff: Duration::from_millis(1),
+        },
+        timeout: Duration::from_secs(5),
+        ..Default::default()
+    }
+}
+
+fn digest_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "checker-email-test-{}-{}.json",
+        process::id(),
+        name
+    ));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+fn entry(row: usize, hour: u32, overdue_minutes: i64) -> AlertEntry {
+    AlertEntry {
+        workbook: "live".to_string(),
+        entry: MissingEntry {
+            sheet: "일정".to_string(),
+            row,
+            scheduled: NaiveDate::from_ymd_opt(2026, 3, 10)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap(),
+            overdue: ChronoDuration::minutes(overdue_minutes),
+            label: Some("<점검>".to_string()),
+        },
+    }
+}
+
+fn alert(entries: Vec<AlertEntry>) -> Alert {
+    Alert {
+        title: "누락 알림".to_string(),
+        message: "확인하세요".to_string(),
+        entries,
+    }
+}
+
+#[test]
+fn sends_plain_and_html_parts() {
+    let (port, mails) = serve();
+    let notifier = EmailNotifier::new(&settings(port), digest_path("direct")).unwrap();
+    notifier.notify(&alert(vec![entry(2, 9, 65)])).unwrap();
+
+    let mails = mails.lock().unwrap();
+    assert_eq!(mails.len(), 1);
+    let parts = parts(&mails[0]);
+    assert_eq!(parts.len(), 2);
+
+    let (content_type, plain) = &parts[0];
+    assert!(content_type.starts_with("text/plain"), "{content_type}");
+    assert!(
+        plain.starts_with("확인하세요\n\n[live / 일정]\n"),
+        "{plain}"
+    );
+    assert!(
+        plain.contains("(2행") && plain.contains("<점검>"),
+        "{plain}"
+    );
+
+    let (content_type, html) = &parts[1];
+    assert!(content_type.starts_with("text/html"), "{content_type}");
+    assert!(html.contains("<h2>누락 알림</h2>"), "{html}");
+    assert!(html.contains("<h3>live / 일정 (1건)</h3>"), "{html}");
+    assert!(
+        html.contains(
+            "<td>2026-03-10 09:00</td><td>2</td><td>1시간 5분 지연</td><td>&lt;점검&gt;</td>"
+        ),
+        "{html}"
+    );
+}
+
+fn at(hour: u32, minute: u32) -> DateTime<Local> {
+    Local
+        .with_ymd_and_hms(2026, 3, 10, hour, minute, 0)
+        .unwrap()
+}
+
+#[test]
+fn digest_collects_until_poll() {
+    let (port, mails) = serve();
+    let path = digest_path("digest");
+    let settings = EmailSettings {
+        digest_at: Some(NaiveTime::from_hms_opt(18, 0, 0).unwrap()),
+        ..settings(port)
+    };
+    let notifier = EmailNotifier::new(&settings, path.clone()).unwrap();
+    notifier.notify(&alert(vec![entry(2, 9, 65)])).unwrap();
+    notifier
+        .notify(&alert(vec![entry(2, 9, 125), entry(3, 10, 65)]))
+        .unwrap();
+    assert!(notifier.poll(at(17, 59)).is_none());
+    assert!(mails.lock().unwrap().is_empty());
+
+    let (title, result) = notifier.poll(at(18, 0)).unwrap();
+    result.unwrap();
+    assert_eq!(title, "누락 항목 일일 요약 (2026-03-10)");
+    assert_eq!(mails.lock().unwrap().len(), 1);
+    // 같은 항목은 한 번만, 마지막 지연 시간으로
+    let (_, html) = &parts(&mails.lock().unwrap()[0])[1];
+    assert!(html.contains("<h3>live / 일정 (2건)</h3>"), "{html}");
+    assert!(html.contains("<td>2</td><td>2시간 5분 지연</td>"), "{html}");
+    assert!(html.contains("<td>3</td><td>1시간 5분 지연</td>"), "{html}");
+
+    // 같은 날에는 다시 보내지 않음
+    assert!(notifier.poll(at(18, 30)).is_none());
+    assert_eq!(mails.lock().unwrap().len(), 1);
+    fs::remove_file(&path).unwrap();
+}
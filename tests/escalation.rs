@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use checker::{
+    alert_state::AlertState,
+    escalation::{EscalationPolicy, EscalationTier},
+    notification::{CheckResult, MissingEntry, WorkbookResult},
+};
+use chrono::{Duration as ChronoDuration, Local, NaiveDate, NaiveDateTime, TimeZone};
+
+fn tier(name: &str, after_minutes: i64, channels: &[&str]) -> EscalationTier {
+    let mut tier = EscalationTier::new(name.to_string());
+    tier.after_minutes = after_minutes;
+    tier.channels = channels.iter().map(|c| c.to_string()).collect();
+    tier
+}
+
+fn policy() -> EscalationPolicy {
+    // 일부러 순서를 섞어 둠
+    EscalationPolicy::new(
+        vec![
+            tier("긴급", 120, &["teams"]),
+            tier("기본", 0, &[]),
+            tier("주의", 30, &["email"]),
+        ],
+        &["console".to_string()],
+    )
+    .unwrap()
+}
+
+#[test]
+fn tier_boundaries_are_inclusive() {
+    let policy = policy();
+    let names: Vec<&str> = policy.tiers().iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, ["기본", "주의", "긴급"]);
+    assert_eq!(policy.tier(0).channels, ["console"]);
+
+    let cases = [
+        (-5, 0),
+        (0, 0),
+        (29, 0),
+        (30, 1),
+        (31, 1),
+        (119, 1),
+        (120, 2),
+        (600, 2),
+    ];
+    for (minutes, expected) in cases {
+        assert_eq!(
+            policy.tier_index(ChronoDuration::minutes(minutes)),
+            expected,
+            "{minutes}분"
+        );
+    }
+    // 분 단위로 내림: 29분 59초는 아직 30분이 아님
+    assert_eq!(policy.tier_index(ChronoDuration::seconds(30 * 60 - 1)), 0);
+}
+
+#[test]
+fn empty_policy_uses_default_tier_and_duplicates_are_rejected() {
+    let policy = EscalationPolicy::new(Vec::new(), &["console".to_string()]).unwrap();
+    assert_eq!(policy.tiers().len(), 1);
+    assert_eq!(policy.tier_index(ChronoDuration::minutes(1000)), 0);
+
+    let error = EscalationPolicy::new(
+        vec![tier("a", 30, &[]), tier("b", 30, &[])],
+        &["console".to_string()],
+    )
+    .unwrap_err();
+    assert_eq!(error, "[tier:a]와 [tier:b]의 after_minutes(30)가 같습니다.");
+}
+
+fn scheduled() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 3, 10)
+        .unwrap()
+        .and_hms_opt(9, 0, 0)
+        .unwrap()
+}
+
+fn result(overdue_minutes: i64) -> CheckResult {
+    let entry = MissingEntry {
+        sheet: "일정".to_string(),
+        row: 2,
+        scheduled: scheduled(),
+        overdue: ChronoDuration::minutes(overdue_minutes),
+        label: None,
+    };
+    CheckResult {
+        workbooks: vec![WorkbookResult {
+            name: "live".to_string(),
+            sheets: vec!["일정".to_string()],
+            missing: HashMap::from([("일정".to_string(), vec![entry])]),
+            ..Default::default()
+        }],
+        window_start: scheduled() - ChronoDuration::days(1),
+    }
+}
+
+#[test]
+fn open_alert_escalates_exactly_at_the_threshold() {
+    let policy = policy();
+    let mut state = AlertState::default();
+    let start = Local.with_ymd_and_hms(2026, 3, 10, 9, 10, 0).unwrap();
+    let at = |minutes: i64| start + ChronoDuration::minutes(minutes - 10);
+
+    let update = state.update(&result(10), at(10), None, &policy);
+    assert_eq!(update.new.len(), 1);
+    assert_eq!(update.new[0].tier, 0);
+
+    let update = state.update(&result(29), at(29), None, &policy);
+    assert!(!update.should_notify());
+
+    let update = state.update(&result(30), at(30), None, &policy);
+    assert_eq!(update.escalated.len(), 1);
+    assert_eq!(update.escalated[0].tier, 1);
+
+    // 같은 단계에서는 재알림 주기가 없으면 다시 알리지 않음
+    let update = state.update(&result(119), at(119), None, &policy);
+    assert!(!update.should_notify());
+
+    let update = state.update(&result(120), at(120), None, &policy);
+    assert_eq!(update.escalated.len(), 1);
+    assert_eq!(update.escalated[0].tier, 2);
+    assert!(update.reminders.is_empty());
+}
+
+#[test]
+fn new_alert_starts_at_its_current_tier() {
+    let policy = policy();
+    let mut state = AlertState::default();
+    let now = Local.with_ymd_and_hms(2026, 3, 10, 11, 0, 0).unwrap();
+    let update = state.update(&result(120), now, None, &policy);
+    assert_eq!(update.new.len(), 1);
+    assert_eq!(update.new[0].tier, 2);
+    assert!(update.escalated.is_empty());
+}
@@ -0,0 +1,150 @@
+use calamine::DataType;
+use checker::formats::DateTimeFormats;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+}
+
+#[test]
+fn parses_time_strings() {
+    let formats = DateTimeFormats::default();
+    let cases = [
+        ("20:16:30", time(20, 16, 30)),
+        ("20:16", time(20, 16, 0)),
+        (" 8:16 ", time(8, 16, 0)),
+        ("8:16 PM", time(20, 16, 0)),
+        ("8:16:05 am", time(8, 16, 5)),
+        ("오후 8:16", time(20, 16, 0)),
+        ("오전  12:05", time(0, 5, 0)),
+        ("오후 8:16:30", time(20, 16, 30)),
+        ("20시 16분", time(20, 16, 0)),
+        ("오후 8시 16분", time(20, 16, 0)),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(formats.parse_time(value), Some(expected), "{value}");
+    }
+    assert_eq!(formats.parse_time("저녁"), None);
+    assert_eq!(formats.parse_time("25:00"), None);
+}
+
+#[test]
+fn parses_date_strings() {
+    let formats = DateTimeFormats::default();
+    let today = date(2025, 6, 1);
+    let cases = [
+        ("2025-04-19", date(2025, 4, 19)),
+        ("2025/04/19", date(2025, 4, 19)),
+        ("2025.04.19", date(2025, 4, 19)),
+        ("2025. 4. 19.", date(2025, 4, 19)),
+        ("2025년 4월 19일", date(2025, 4, 19)),
+        ("4월 19일", date(2025, 4, 19)),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(formats.parse_date(value, today), Some(expected), "{value}");
+    }
+    assert_eq!(formats.parse_date("2025-02-30", today), None);
+    assert_eq!(formats.parse_date("어제", today), None);
+}
+
+#[test]
+fn yearless_dates_near_new_year_use_last_year() {
+    let formats = DateTimeFormats::default();
+    // 1월 초에 본 12월 말 날짜는 작년
+    assert_eq!(
+        formats.parse_date("12월 30일", date(2026, 1, 2)),
+        Some(date(2025, 12, 30))
+    );
+    // 12월 말에 본 1월 초 날짜는 올해 1월 (이미 지난 날짜)
+    assert_eq!(
+        formats.parse_date("1월 2일", date(2025, 12, 30)),
+        Some(date(2025, 1, 2))
+    );
+    // 180일 이내의 미래는 올해
+    assert_eq!(
+        formats.parse_date("7월 1일", date(2026, 3, 10)),
+        Some(date(2026, 7, 1))
+    );
+}
+
+#[test]
+fn parses_datetime_strings() {
+    let formats = DateTimeFormats::default();
+    let today = date(2025, 6, 1);
+    let at = |d: NaiveDate, t: NaiveTime| NaiveDateTime::new(d, t);
+    let cases = [
+        (
+            "2025-04-19T20:16:00",
+            at(date(2025, 4, 19), time(20, 16, 0)),
+        ),
+        ("2025-04-19 20:16", at(date(2025, 4, 19), time(20, 16, 0))),
+        (
+            "2025/04/19 오후 8:16",
+            at(date(2025, 4, 19), time(20, 16, 0)),
+        ),
+        ("4월 19일 8:16 PM", at(date(2025, 4, 19), time(20, 16, 0))),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(
+            formats.parse_datetime(value, today),
+            Some(expected),
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn custom_formats_are_tried_after_the_defaults() {
+    let mut formats = DateTimeFormats::default();
+    assert_eq!(formats.parse_date("19-04-2025", date(2025, 6, 1)), None);
+    formats.add_date("%d-%m-%Y").unwrap();
+    assert_eq!(
+        formats.parse_date("19-04-2025", date(2025, 6, 1)),
+        Some(date(2025, 4, 19))
+    );
+    assert!(formats.add_time("%Q").is_err());
+}
+
+#[test]
+fn numeric_and_string_cells() {
+    let formats = DateTimeFormats::default();
+    let today = date(2025, 6, 1);
+    // 일련번호 45766 = 2025-04-19, 소수부 0.5 = 12:00, 0.75 = 18:00
+    assert_eq!(
+        formats.cell_to_date(Some(&DataType::DateTime(45766.0)), today),
+        Ok(Some(date(2025, 4, 19)))
+    );
+    assert_eq!(
+        formats.cell_to_date(Some(&DataType::Int(45766)), today),
+        Ok(Some(date(2025, 4, 19)))
+    );
+    assert_eq!(
+        formats.cell_to_time(Some(&DataType::Float(0.5))),
+        Ok(Some(time(12, 0, 0)))
+    );
+    assert_eq!(
+        formats.cell_to_time(Some(&DataType::String("오후 8:16".to_string()))),
+        Ok(Some(time(20, 16, 0)))
+    );
+    assert_eq!(
+        formats.cell_to_datetime(Some(&DataType::DateTime(45766.75)), today),
+        Ok(Some(NaiveDateTime::new(date(2025, 4, 19), time(18, 0, 0))))
+    );
+
+    // 빈 셀은 값 없음, 형식이 맞지 않거나 숫자로 볼 수 없는 셀은 오류
+    assert_eq!(formats.cell_to_date(None, today), Ok(None));
+    assert_eq!(
+        formats.cell_to_time(Some(&DataType::String("  ".to_string()))),
+        Ok(None)
+    );
+    assert!(
+        formats
+            .cell_to_date(Some(&DataType::String("날짜".to_string())), today)
+            .is_err()
+    );
+    assert!(formats.cell_to_time(Some(&DataType::Bool(true))).is_err());
+}
@@ -0,0 +1,114 @@
+use checker::schedule::{CronExpr, Schedule, Scheduler};
+use chrono::{DateTime, Duration as ChronoDuration, NaiveDate, NaiveDateTime, TimeZone};
+use chrono_tz::{Europe::Berlin, Tz};
+
+// 베를린 2026년 서머타임: 3월 29일 02:00 → 03:00, 10월 25일 03:00 → 02:00
+fn wall(month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, month, day)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
+
+fn berlin(month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Tz> {
+    Berlin
+        .from_local_datetime(&wall(month, day, hour, minute))
+        .earliest()
+        .unwrap()
+}
+
+// 다음 예정 시각에 실행하고 그 예정 시각을 반환
+fn fire(scheduler: &mut Scheduler, now: &DateTime<Tz>) -> NaiveDateTime {
+    let next = scheduler.next_fire(now).unwrap();
+    assert!(
+        next.at <= *now,
+        "{} 이전에 실행할 일정 없음: {:?}",
+        now,
+        next
+    );
+    scheduler.mark_fired(next.slot, now);
+    next.slot
+}
+
+#[test]
+fn repeated_hour_fires_once() {
+    let mut scheduler = Scheduler::new(Schedule::minutes(vec![30]).unwrap());
+    let first = berlin(10, 25, 2, 30);
+    assert_eq!(fire(&mut scheduler, &first), wall(10, 25, 2, 30));
+
+    // 서머타임이 끝나 02:30이 다시 옴
+    let repeated = first + ChronoDuration::hours(1);
+    assert_eq!(repeated.naive_local(), wall(10, 25, 2, 30));
+    let next = scheduler.next_fire(&repeated).unwrap();
+    assert_eq!(next.slot, wall(10, 25, 3, 30));
+    assert!(next.at > repeated);
+}
+
+#[test]
+fn skipped_slot_fires_right_after_the_gap() {
+    let mut scheduler = Scheduler::new(Schedule::minutes(vec![30]).unwrap());
+    fire(&mut scheduler, &berlin(3, 29, 1, 30));
+
+    let next = scheduler.next_fire(&berlin(3, 29, 1, 45)).unwrap();
+    assert_eq!(next.slot, wall(3, 29, 2, 30));
+    assert_eq!(next.at, berlin(3, 29, 3, 0));
+}
+
+#[test]
+fn forward_jump_fires_once() {
+    let mut scheduler = Scheduler::new(Schedule::minutes(vec![0, 30]).unwrap());
+    fire(&mut scheduler, &berlin(6, 1, 10, 0));
+
+    // 10:30, 11:00, 11:30, 12:00을 놓침
+    let jumped = berlin(6, 1, 12, 10);
+    assert_eq!(fire(&mut scheduler, &jumped), wall(6, 1, 10, 30));
+    let next = scheduler.next_fire(&jumped).unwrap();
+    assert_eq!(next.slot, wall(6, 1, 12, 30));
+}
+
+#[test]
+fn same_slot_does_not_fire_twice() {
+    let mut scheduler = Scheduler::new(Schedule::minutes(vec![0, 30]).unwrap());
+    let now = berlin(6, 1, 10, 30);
+    fire(&mut scheduler, &now);
+
+    let later = now + ChronoDuration::seconds(40);
+    let next = scheduler.next_fire(&later).unwrap();
+    assert_eq!(next.slot, wall(6, 1, 11, 0));
+    assert!(next.at > later);
+}
+
+#[test]
+fn clock_going_back_an_hour_recalculates() {
+    let mut scheduler = Scheduler::new(Schedule::minutes(vec![0, 30]).unwrap());
+    fire(&mut scheduler, &berlin(6, 1, 10, 30));
+
+    // 1시간 미만이면 이미 실행한 예정 시각을 다시 실행하지 않음
+    let next = scheduler.next_fire(&berlin(6, 1, 10, 0)).unwrap();
+    assert_eq!(next.slot, wall(6, 1, 11, 0));
+
+    let next = scheduler.next_fire(&berlin(6, 1, 9, 20)).unwrap();
+    assert_eq!(next.slot, wall(6, 1, 9, 30));
+}
+
+#[test]
+fn cron_fields_covering_every_value_are_unrestricted() {
+    // 2026-06-01은 월요일, 6월 13일은 토요일
+    let days = |source: &str| -> Vec<u32> {
+        let expr = CronExpr::parse(source).unwrap();
+        (1..=30)
+            .filter(|day| expr.matches(wall(6, *day, 9, 0)))
+            .collect()
+    };
+    let mondays = [1, 8, 15, 22, 29];
+    assert_eq!(days("0 9 13 * *"), [13]);
+    assert_eq!(days("0 9 13 * */1"), [13]);
+    assert_eq!(days("0 9 13 * 0-6"), [13]);
+    assert_eq!(days("0 9 13 * 0-7"), [13]);
+    assert_eq!(days("0 9 * * 1"), mondays);
+    assert_eq!(days("0 9 */1 * 1"), mondays);
+    assert_eq!(days("0 9 1-31 * 1"), mondays);
+    // 일과 요일을 모두 제한하면 둘 중 하나만 맞아도 실행
+    assert_eq!(days("0 9 13 * 1"), [1, 8, 13, 15, 22, 29]);
+    assert_eq!(days("0 9 */2 * 1-5").len(), 26);
+}
@@ -0,0 +1,75 @@
+use std::path::Path;
+
+use calamine::DataType;
+use checker::source::{CsvSource, SheetSource, SourceFormat, resolve_format, source_from_bytes};
+
+fn text(value: &str) -> DataType {
+    DataType::String(value.to_string())
+}
+
+#[test]
+fn csv_cells_are_typed_like_excel_cells() {
+    let csv = "\
+날짜,시간,완료,비고
+2026-03-10,09:00,TRUE,\"쉼표, 포함\"
+2026-03-10,10:30, 1.5 ,
+,,false
+";
+    let mut source = CsvSource::from_reader(csv.as_bytes()).unwrap();
+    let range = source.sheet_range("아무 이름").unwrap();
+    assert_eq!(range.get_size(), (4, 4));
+    assert_eq!(range.get_value((0, 2)), Some(&text("완료")));
+    assert_eq!(range.get_value((1, 0)), Some(&text("2026-03-10")));
+    assert_eq!(range.get_value((1, 2)), Some(&DataType::Bool(true)));
+    assert_eq!(range.get_value((1, 3)), Some(&text("쉼표, 포함")));
+    assert_eq!(range.get_value((2, 2)), Some(&DataType::Float(1.5)));
+    assert_eq!(range.get_value((2, 3)), Some(&DataType::Empty));
+    assert_eq!(range.get_value((3, 0)), Some(&DataType::Empty));
+    assert_eq!(range.get_value((3, 2)), Some(&DataType::Bool(false)));
+    // 짧은 행의 나머지 칸은 빈 셀
+    assert_eq!(range.get_value((3, 3)), Some(&DataType::Empty));
+
+    // 어떤 시트 이름으로 요청해도 같은 내용
+    let again = source.sheet_range("일정").unwrap();
+    assert_eq!(
+        again.cells().collect::<Vec<_>>(),
+        range.cells().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn empty_csv_is_an_empty_sheet() {
+    let mut source = CsvSource::from_reader("".as_bytes()).unwrap();
+    assert!(source.sheet_range("일정").unwrap().is_empty());
+}
+
+#[test]
+fn invalid_utf8_is_reported_with_the_row() {
+    let error = CsvSource::from_reader(&b"a,b\n\xff\xfe,c\n"[..])
+        .err()
+        .unwrap();
+    assert!(error.starts_with("CSV 2행 읽기 실패"), "{error}");
+
+    let error = source_from_bytes(b"a\n\xff\n".to_vec(), SourceFormat::Csv)
+        .err()
+        .unwrap();
+    assert!(error.starts_with("CSV 2행 읽기 실패"), "{error}");
+}
+
+#[test]
+fn format_comes_from_setting_or_extension() {
+    assert_eq!(
+        resolve_format(Path::new("일정.CSV"), None),
+        SourceFormat::Csv
+    );
+    assert_eq!(
+        resolve_format(Path::new("일정.csv"), Some(SourceFormat::Xlsx)),
+        SourceFormat::Xlsx
+    );
+    assert_eq!(
+        resolve_format(Path::new("일정.txt"), None),
+        SourceFormat::Xlsx
+    );
+    assert_eq!(SourceFormat::parse(" XLSM "), Ok(SourceFormat::Xlsx));
+    assert!(SourceFormat::parse("numbers").is_err());
+}
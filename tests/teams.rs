@@ -0,0 +1,82 @@
+mod common;
+
+use std::time::Duration;
+
+use checker::{
+    notification::MissingEntry,
+    notifier::{Alert, AlertEntry, Notifier, RetryPolicy},
+    teams::{CardFormat, TeamsNotifier},
+};
+use chrono::{Duration as ChronoDuration, NaiveDate};
+use common::serve;
+use serde_json::Value;
+
+fn notifier(url: String, retries: u32) -> TeamsNotifier {
+    let retry = RetryPolicy {
+        retries,
+        backoff: Duration::from_millis(1),
+    };
+    TeamsNotifier::new(url, CardFormat::Adaptive, retry, Duration::from_secs(5))
+}
+
+fn alert() -> Alert {
+    let scheduled = NaiveDate::from_ymd_opt(2026, 3, 10)
+        .unwrap()
+        .and_hms_opt(9, 0, 0)
+        .unwrap();
+    Alert {
+        title: "누락 1건".to_string(),
+        message: "확인하세요".to_string(),
+        entries: vec![AlertEntry {
+            workbook: "live".to_string(),
+            entry: MissingEntry {
+                sheet: "일정".to_string(),
+                row: 2,
+                scheduled,
+                overdue: ChronoDuration::minutes(65),
+                label: Some("점검".to_string()),
+            },
+        }],
+    }
+}
+
+#[test]
+fn posts_adaptive_card() {
+    let (url, bodies) = serve(vec![200]);
+    notifier(url, 0).notify(&alert()).unwrap();
+
+    let bodies = bodies.lock().unwrap();
+    assert_eq!(bodies.len(), 1);
+    let card: Value = serde_json::from_str(&bodies[0]).unwrap();
+    let attachment = &card["attachments"][0];
+    assert_eq!(
+        attachment["contentType"],
+        "application/vnd.microsoft.card.adaptive"
+    );
+    let body = &attachment["content"]["body"];
+    assert_eq!(body[0]["text"], "누락 1건");
+    assert_eq!(body[1]["text"], "확인하세요");
+    assert_eq!(body[2]["text"], "live / 일정 (1건)");
+    assert_eq!(body[3]["facts"][0]["title"], "03-10 09:00 (2행)");
+    assert_eq!(body[3]["facts"][0]["value"], "1시간 5분 지연 · 점검");
+}
+
+#[test]
+fn retries_server_errors() {
+    let (url, bodies) = serve(vec![503, 500, 200]);
+    notifier(url, 2).notify(&alert()).unwrap();
+    assert_eq!(bodies.lock().unwrap().len(), 3);
+
+    let (url, bodies) = serve(vec![500, 500, 500]);
+    let error = notifier(url, 2).notify(&alert()).unwrap_err();
+    assert!(error.contains("3회 시도"), "{error}");
+    assert_eq!(bodies.lock().unwrap().len(), 3);
+}
+
+#[test]
+fn does_not_retry_client_errors() {
+    let (url, bodies) = serve(vec![400]);
+    let error = notifier(url, 3).notify(&alert()).unwrap_err();
+    assert!(error.starts_with("HTTP 400"), "{error}");
+    assert_eq!(bodies.lock().unwrap().len(), 1);
+}
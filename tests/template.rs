@@ -0,0 +1,143 @@
+use std::{fs, process};
+
+use checker::{
+    config_file::read_config,
+    notification::MissingEntry,
+    notifier::AlertEntry,
+    template::{Template, TemplateContext},
+};
+use chrono::{Duration as ChronoDuration, Local, NaiveDate, TimeZone};
+
+fn entry(workbook: &str, sheet: &str, row: usize, hour: u32, overdue_minutes: i64) -> AlertEntry {
+    AlertEntry {
+        workbook: workbook.to_string(),
+        entry: MissingEntry {
+            sheet: sheet.to_string(),
+            row,
+            scheduled: NaiveDate::from_ymd_opt(2026, 3, 10)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap(),
+            overdue: ChronoDuration::minutes(overdue_minutes),
+            label: (row == 2).then(|| "점검".to_string()),
+        },
+    }
+}
+
+fn render(source: &str, entries: &[AlertEntry]) -> String {
+    let context = TemplateContext {
+        count: entries.len(),
+        new: 1,
+        total: 5,
+        now: Local.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap(),
+        entries,
+    };
+    Template::parse(source).unwrap().render(&context)
+}
+
+#[test]
+fn fills_global_placeholders() {
+    let entries = [
+        entry("live", "일정", 2, 9, 180),
+        entry("live", "점검", 5, 10, 120),
+        entry("live", "일정", 7, 11, 60),
+    ];
+    assert_eq!(
+        render(
+            "{count}건 (새 {new}, 전체 {total}) {sheets} {sheet_count}개 시트",
+            &entries
+        ),
+        "3건 (새 1, 전체 5) 일정, 점검 2개 시트"
+    );
+    assert_eq!(
+        render("{now} / {earliest} / {max_overdue}", &entries),
+        "2026-03-10 12:00 / 2026-03-10 09:00 / 3시간"
+    );
+    // 워크북이 여러 개면 시트 이름 앞에 워크북 이름
+    let entries = [entry("a", "일정", 2, 9, 5), entry("b", "일정", 3, 9, 5)];
+    assert_eq!(render("{ sheets }", &entries), "a/일정, b/일정");
+}
+
+#[test]
+fn repeats_entry_loop() {
+    let entries = [
+        entry("live", "일정", 2, 9, 65),
+        entry("live", "일정", 3, 10, 5),
+    ];
+    let source = "\
+누락 {count}건
+{#entries}
+- {sheet} {row}행 {scheduled} ({overdue}) {label}
+{/entries}
+끝";
+    assert_eq!(
+        render(source, &entries),
+        "누락 2건\n- 일정 2행 2026-03-10 09:00 (1시간 5분) 점검\n- 일정 3행 2026-03-10 10:00 (5분) \n끝"
+    );
+    assert_eq!(render("[{#entries}{row},{/entries}]", &entries), "[2,3,]");
+}
+
+#[test]
+fn doubled_braces_are_literal() {
+    assert_eq!(render("{{count}} = {count}}}", &[]), "{count} = 0}");
+}
+
+#[test]
+fn rejects_invalid_templates() {
+    let cases = [
+        ("{unknown}", "알 수 없는 자리표시자입니다: '{unknown}'"),
+        ("{row}", "'{row}'는 {#entries} ... {/entries} 안에서만"),
+        ("{count", "'{' 뒤에 '}'가 없습니다."),
+        ("count}", "'}' 앞에 '{'가 없습니다."),
+        (
+            "{#entries}{row}",
+            "{#entries}가 {/entries}로 닫히지 않았습니다.",
+        ),
+        ("{/entries}", "{/entries} 앞에 {#entries}가 없습니다."),
+        (
+            "{#entries}{#entries}{/entries}{/entries}",
+            "{#entries} 안에 다시 {#entries}를",
+        ),
+    ];
+    for (source, expected) in cases {
+        let error = Template::parse(source).unwrap_err();
+        assert!(error.starts_with(expected), "{source}: {error}");
+    }
+}
+
+#[test]
+fn ini_message_keeps_blank_and_hash_lines() {
+    let dir = std::env::temp_dir().join(format!("checker-template-test-{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("config.cfg");
+    fs::write(
+        &path,
+        "\
+[workbook:live]
+path = live.xlsx
+sheets = t
+
+[title]
+; 주석이 아닌 제목
+[message]
+
+  {count}건 누락
+
+#3 확인 필요
+; 담당자 연락
+
+[grace]
+minutes = 3
+",
+    )
+    .unwrap();
+    let config = read_config(&path).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(config.notification_title.to_string(), "; 주석이 아닌 제목");
+    assert_eq!(
+        config.notification_message_template.to_string(),
+        "  {count}건 누락\n\n#3 확인 필요\n; 담당자 연락"
+    );
+    assert_eq!(config.grace_minutes, 3);
+}
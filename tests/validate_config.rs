@@ -0,0 +1,70 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+// 임시 폴더에 워크북 파일과 설정을 만들고 validate-config 실행. (종료 코드, 표준 출력)
+fn validate(name: &str, workbook: &str, extra: &str) -> (i32, String) {
+    let dir =
+        std::env::temp_dir().join(format!("checker-validate-test-{}-{}", process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("live.xlsx"), b"").unwrap();
+    let config = write_config(&dir, workbook, extra);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_checker"))
+        .args(["validate-config", "--config"])
+        .arg(&config)
+        .current_dir(&dir)
+        .env_remove("RUST_LOG")
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    (
+        output.status.code().unwrap(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    )
+}
+
+fn write_config(dir: &Path, workbook: &str, extra: &str) -> PathBuf {
+    let path = dir.join("config.toml");
+    fs::write(
+        &path,
+        format!("[[workbook]]\nname = \"live\"\npath = '{workbook}'\nsheets = \"t\"\n{extra}"),
+    )
+    .unwrap();
+    path
+}
+
+#[test]
+fn clean_config_passes() {
+    let (code, stdout) = validate("clean", "live.xlsx", "");
+    assert_eq!(code, 0, "{stdout}");
+    assert!(stdout.ends_with("설정에 오류가 없습니다.\n"), "{stdout}");
+}
+
+#[test]
+fn warnings_are_summarized() {
+    let (code, stdout) = validate("warn", "live.xlsx", "[sheet.other]\ngrace = 3\n");
+    assert_eq!(code, 0, "{stdout}");
+    assert!(
+        stdout.ends_with("설정 경고 1건이 있습니다. 위 경고를 확인하세요.\n"),
+        "{stdout}"
+    );
+    assert!(!stdout.contains("설정에 오류가 없습니다."), "{stdout}");
+}
+
+#[test]
+fn missing_workbook_fails() {
+    let (code, stdout) = validate("missing", "nope.xlsx", "");
+    assert_eq!(code, 2, "{stdout}");
+    assert!(
+        stdout.contains(" ! 파일을 찾을 수 없습니다: nope.xlsx"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.ends_with("워크북 파일 1개를 찾을 수 없습니다.\n"),
+        "{stdout}"
+    );
+}